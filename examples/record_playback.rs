@@ -0,0 +1,37 @@
+use std::error::Error;
+
+use wiremock_client::*;
+use wiremock_client::recording::RecordingStatus;
+
+// Examples from: http://wiremock.org/docs/record-playback/
+pub fn main() -> Result<(), Box<dyn Error>> {
+    let wire_mock = WireMockBuilder::new()
+        .port(8181) // If running on another port - the default is: 8080
+        .build();
+
+    // Recording
+    wire_mock.start_recording("http://example.mocklab.io")?;
+    assert_eq!(wire_mock.get_recording_status()?, RecordingStatus::Recording);
+    let _recorded_mappings = wire_mock.stop_recording()?;
+
+    // Customising your recordings
+    wire_mock.start_recording(record_spec()
+        .for_target("http://example.mocklab.io")
+        .only_requests_matching(get_requested_for(url_path_matching("/api/.*")))
+        .capture_header("Accept")
+        .capture_header_case_insensitive("Content-Type", true)
+        .extract_binary_bodies_over(10240)
+        .extract_text_bodies_over(2048)
+        .make_stubs_persistent(false)
+        .ignore_repeat_requests()
+        .transformers(vec!["modify-response-header"])
+        .match_request_body_with_equal_to_json(false, true))?;
+    wire_mock.stop_recording()?;
+
+    // Snapshotting
+    let _snapshot_mappings = wire_mock.snapshot_record(record_spec()
+        .only_requests_matching(get_requested_for(url_path_matching("/api/.*")))
+        .make_stubs_persistent(false))?;
+
+    Ok(())
+}
@@ -38,6 +38,11 @@ pub fn main() -> Result<(), Box<dyn Error>> {
         wire_mock.verify_count(more_than(5), post_requested_for(url_equal_to("/many")));
     });
 
+    // Or get the failure back instead of panicking
+    if let Err(error) = wire_mock.try_verify(post_requested_for(url_equal_to("/verify/this"))) {
+        println!("{}", error);
+    }
+
     // Querying the request journal
     // Getting all requests
     let _all_serve_events: Vec<ServeEvent> = wire_mock.get_serve_events()?;
@@ -52,7 +57,7 @@ pub fn main() -> Result<(), Box<dyn Error>> {
 
     // Near misses
     // .. representing stub mappings closest to the specified logged request
-    if let Some(logged_request) = unmatched.get(0) {
+    if let Some(logged_request) = unmatched.first() {
         let _near_misses: Vec<NearMiss> = wire_mock.find_near_misses_for_request(logged_request)?;
     }
     // .. representing stub mappings closest to the specified request
@@ -67,5 +72,5 @@ pub fn main() -> Result<(), Box<dyn Error>> {
 }
 
 pub fn ignore_panic<F: FnOnce()>(f: F) {
-    let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| f()));
+    let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(f));
 }
@@ -0,0 +1,640 @@
+use std::borrow::Cow;
+use std::panic;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use futures_util::stream::{self, Stream};
+use http::HeaderValue;
+use reqwest_async::{Method, RequestBuilder, Response};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::client::{a_response, count_found_requests, error_status, file_path, filter_inline_custom_matcher, map_not_found_error_to, map_not_found_error_to_none, map_scenario_error, more_than_or_exactly, parse_json, scenario_state_path, serve_event_pages::ServeEventWalk, stub_import_from_dir, ResponseDefinitionBuilder, ScenarioState, WireMockBuilder};
+use crate::error::{Error, Result};
+use crate::global::GlobalSettings;
+use crate::http::DelayDistribution;
+use crate::matching::{ContentPattern, CountMatchingMode, CountMatchingStrategy, RequestPattern, StringValuePattern};
+use crate::recording::{RecordingStatus, RecordingStatusResult, RecordSpec, SnapshotRecordResult};
+use crate::model::{GetGlobalSettingsResult, GetScenariosResult, GetServeEventsResult, ListStubMappingsResult, PageParams, SingleServedStubResult, SingleStubMappingResult};
+use crate::security::ClientAuthenticator;
+use crate::stubbing::{write_mappings_dir, MappingsExportOptions, Scenario, ServeEvent, StubImport, StubImportDuplicatePolicy, StubMapping};
+use crate::verification::{FindNearMissesResult, FindRequestsResult, FindServeEventsResult, JournalBasedResult, LoggedRequest, NearMiss, VerificationError, VerificationResult};
+
+/// Non-blocking counterpart of [`WireMock`](struct.WireMock.html).
+///
+/// Offers the same admin operations as `async fn`s and shares all request and response model types.
+/// It has to be used from within a tokio runtime.
+pub struct AsyncWireMock {
+    client: reqwest_async::Client,
+    scheme: String,
+    host: String,
+    port: u16,
+    url_path_prefix: String,
+    host_header: Option<HeaderValue>,
+    authenticator: Box<dyn ClientAuthenticator + Send + Sync>,
+}
+
+impl Default for AsyncWireMock {
+    fn default() -> AsyncWireMock {
+        WireMockBuilder::new().build_async()
+    }
+}
+
+impl<A: ClientAuthenticator + Send + Sync + 'static> From<WireMockBuilder<A>> for AsyncWireMock {
+    fn from(builder: WireMockBuilder<A>) -> AsyncWireMock {
+        builder.build_async()
+    }
+}
+
+impl AsyncWireMock {
+    pub(crate) fn new(
+        scheme: String,
+        host: String,
+        port: u16,
+        url_path_prefix: String,
+        host_header: Option<HeaderValue>,
+        authenticator: Box<dyn ClientAuthenticator + Send + Sync>,
+    ) -> AsyncWireMock {
+        AsyncWireMock {
+            client: reqwest_async::Client::new(),
+            scheme,
+            host,
+            port,
+            url_path_prefix,
+            host_header,
+            authenticator,
+        }
+    }
+
+    pub fn host(&self) -> &str {
+        &self.host
+    }
+
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
+    pub async fn given_that<S: Into<StubMapping>>(&self, stub_mapping: S) -> Result<StubMapping> {
+        let stub_mapping = stub_mapping.into();
+        self.add_stub_mapping(&stub_mapping).await?;
+        Ok(stub_mapping)
+    }
+
+    pub async fn stub_for<S: Into<StubMapping>>(&self, stub_mapping: S) -> Result<StubMapping> {
+        self.given_that(stub_mapping).await
+    }
+
+    pub async fn add_stub_mapping(&self, stub_mapping: &StubMapping) -> Result<()> {
+        self.send_json_request(Method::POST, "/mappings", stub_mapping).await
+            .map(|_| ())
+    }
+
+    pub async fn edit_stub_mapping(&self, stub_mapping: &StubMapping) -> Result<()> {
+        self.send_json_request(Method::PUT, &format!("/mappings/{}", stub_mapping.id), stub_mapping).await
+            .map(|_| ())
+    }
+
+    pub async fn edit_stub<S: Into<StubMapping>>(&self, stub_mapping: S) -> Result<StubMapping> {
+        let stub_mapping = stub_mapping.into();
+        self.edit_stub_mapping(&stub_mapping).await?;
+        Ok(stub_mapping)
+    }
+
+    pub async fn remove_stub_mapping(&self, id: &Uuid) -> Result<bool> {
+        self.send_empty_request(Method::DELETE, &format!("/mappings/{}", id)).await
+            .map(|_| true)
+            .or_else(|error| map_not_found_error_to(error, false))
+    }
+
+    pub async fn remove_stub<S: Into<StubMapping>>(&self, stub_mapping: S) -> Result<bool> {
+        let stub_mapping = stub_mapping.into();
+        self.remove_stub_mapping(stub_mapping.id()).await
+    }
+
+    pub async fn list_all_stub_mappings(&self) -> Result<ListStubMappingsResult> {
+        let response = self.send_empty_request(Method::GET, "/").await?;
+        read_json::<ListStubMappingsResult>(response).await
+    }
+
+    pub async fn list_stub_mappings(&self) -> Result<Vec<StubMapping>> {
+        self.list_all_stub_mappings().await
+            .map(ListStubMappingsResult::into)
+    }
+
+    pub async fn get_stub_mapping(&self, id: &Uuid) -> Result<Option<StubMapping>> {
+        let response = match self.send_empty_request(Method::GET, &format!("/mappings/{}", id)).await {
+            Ok(response) => response,
+            Err(error) => return map_not_found_error_to_none(error),
+        };
+        read_json::<SingleStubMappingResult>(response).await
+            .map(|result| Some(result.into()))
+    }
+
+    pub async fn save_mappings(&self) -> Result<()> {
+        self.send_empty_request(Method::POST, "/mappings/save").await
+            .map(|_| ())
+    }
+
+    pub async fn reset_all(&self) -> Result<()> {
+        self.send_empty_request(Method::POST, "/reset").await
+            .map(|_| ())
+    }
+
+    pub async fn reset_requests(&self) -> Result<()> {
+        self.send_empty_request(Method::DELETE, "/requests").await
+            .map(|_| ())
+    }
+
+    pub async fn reset_scenarios(&self) -> Result<()> {
+        self.send_empty_request(Method::POST, "/scenarios/reset").await
+            .map(|_| ())
+    }
+
+    pub async fn set_scenario_state(&self, scenario_name: &str, state: &str) -> Result<()> {
+        let path = scenario_state_path(scenario_name);
+        let scenario_state = ScenarioState { state };
+        self.send_json_request(Method::PUT, &path, &scenario_state).await
+            .map(|_| ())
+            .map_err(|error| map_scenario_error(error, scenario_name, Some(state)))
+    }
+
+    pub async fn reset_scenario(&self, scenario_name: &str) -> Result<()> {
+        let path = scenario_state_path(scenario_name);
+        self.send_empty_request(Method::PUT, &path).await
+            .map(|_| ())
+            .map_err(|error| map_scenario_error(error, scenario_name, None))
+    }
+
+    pub async fn reset_mappings(&self) -> Result<()> {
+        self.send_empty_request(Method::DELETE, "/mappings").await
+            .map(|_| ())
+    }
+
+    pub async fn reset_to_default_mappings(&self) -> Result<()> {
+        self.send_empty_request(Method::POST, "/mappings/reset").await
+            .map(|_| ())
+    }
+
+    pub async fn verify<'a, P>(&self, request_pattern: P)
+        where P: Into<Cow<'a, RequestPattern>>,
+    {
+        self.verify_count(more_than_or_exactly(1), request_pattern).await;
+    }
+
+    pub async fn verify_count<'a, C, P>(&self, expected_count: C, request_pattern: P)
+        where C: Into<CountMatchingStrategy>,
+              P: Into<Cow<'a, RequestPattern>>,
+    {
+        if let Err(error) = self.try_verify_count(expected_count, request_pattern).await {
+            panic!("{}", error);
+        }
+    }
+
+    pub async fn try_verify<'a, P>(&self, request_pattern: P) -> Result<()>
+        where P: Into<Cow<'a, RequestPattern>>,
+    {
+        self.try_verify_count(more_than_or_exactly(1), request_pattern).await
+    }
+
+    pub async fn try_verify_count<'a, C, P>(&self, expected_count: C, request_pattern: P) -> Result<()>
+        where C: Into<CountMatchingStrategy>,
+              P: Into<Cow<'a, RequestPattern>>,
+    {
+        let expected_count = expected_count.into();
+        let request_pattern = request_pattern.into();
+
+        let actual_count = self.count(request_pattern.as_ref()).await?;
+
+        if expected_count.is_match(actual_count) {
+            return Ok(());
+        }
+
+        // near misses are only a diagnostic and must not hide the count mismatch
+        let near_misses = self.find_near_misses_for(request_pattern.as_ref()).await.unwrap_or_default();
+        Err(VerificationError::new(expected_count, actual_count, request_pattern.into_owned(), near_misses).into())
+    }
+
+    pub async fn get_serve_events(&self) -> Result<Vec<ServeEvent>> {
+        let response = self.send_empty_request(Method::GET, "/requests").await?;
+        read_json::<GetServeEventsResult>(response).await
+            .map(GetServeEventsResult::into)
+    }
+
+    pub async fn get_serve_events_page(&self, page_params: PageParams) -> Result<GetServeEventsResult> {
+        let response = self.send_query_request(Method::GET, "/requests", &page_params).await?;
+        read_json::<GetServeEventsResult>(response).await
+    }
+
+    pub async fn get_serve_events_since(&self, since: SystemTime) -> Result<Vec<ServeEvent>> {
+        self.get_serve_events_page(PageParams::default().with_since(since)).await
+            .map(GetServeEventsResult::into)
+    }
+
+    /// Walks the request journal like [`WireMock::serve_events_pages`](struct.WireMock.html#method.serve_events_pages),
+    /// fetching every page when the stream is polled.
+    pub fn serve_events_pages(&self, page_params: PageParams) -> impl Stream<Item = Result<Vec<ServeEvent>>> + '_ {
+        stream::unfold(ServeEventWalk::new(page_params), move |mut walk| async move {
+            let page = match walk.next_page_params()? {
+                Ok(page_params) => {
+                    let result = self.get_serve_events_page(page_params).await;
+                    walk.read_page(page_params, result)?
+                },
+                Err(error) => Err(error),
+            };
+            Some((page, walk))
+        })
+    }
+
+    pub async fn get_served_stub(&self, id: &Uuid) -> Result<Option<ServeEvent>> {
+        let response = match self.send_empty_request(Method::GET, &format!("/requests/{}", id)).await {
+            Ok(response) => response,
+            Err(error) => return map_not_found_error_to_none(error),
+        };
+        read_json::<SingleServedStubResult>(response).await
+            .map(|result| Some(result.into()))
+    }
+
+    pub async fn remove_serve_event(&self, id: &Uuid) -> Result<Option<ServeEvent>> {
+        let serve_event = match self.get_served_stub(id).await? {
+            Some(serve_event) => serve_event,
+            None => return Ok(None),
+        };
+        self.send_empty_request(Method::DELETE, &format!("/requests/{}", id)).await
+            .map(|_| Some(serve_event))
+            .or_else(map_not_found_error_to_none)
+    }
+
+    /// Fails with `Error::UnsupportedInlineCustomMatcher` if the pattern has an inline custom matcher.
+    pub async fn remove_serve_events_matching<'a, P>(&self, request_pattern: P) -> Result<Vec<ServeEvent>>
+        where P: Into<Cow<'a, RequestPattern>>,
+    {
+        let request_pattern = request_pattern.into();
+        if request_pattern.has_inline_custom_matcher() {
+            return Err(Error::UnsupportedInlineCustomMatcher);
+        }
+        let response = self.send_json_request(Method::POST, "/requests/remove", &request_pattern).await?;
+        let result = read_json::<FindServeEventsResult>(response).await?;
+        result.check_request_journal_enabled()?;
+        Ok(result.into())
+    }
+
+    pub async fn remove_serve_events_for_stubs_by_metadata<P>(&self, pattern: P) -> Result<Vec<ServeEvent>>
+        where P: StringValuePattern + Sized,
+    {
+        let content_pattern: ContentPattern = pattern.into();
+        let response = self.send_json_request(Method::POST, "/requests/remove-by-metadata", &content_pattern).await?;
+        let result = read_json::<FindServeEventsResult>(response).await?;
+        result.check_request_journal_enabled()?;
+        Ok(result.into())
+    }
+
+    /// Counts the requests matching the pattern, applying an inline custom matcher client-side.
+    pub async fn count_requests_matching<'a, P>(&self, request_pattern: P) -> Result<VerificationResult>
+        where P: Into<Cow<'a, RequestPattern>>,
+    {
+        let request_pattern = request_pattern.into();
+        if request_pattern.has_inline_custom_matcher() {
+            return self.find_requests_matching(request_pattern).await.map(count_found_requests);
+        }
+        let response = self.send_json_request(Method::POST, "/requests/count", &request_pattern).await?;
+        read_json::<VerificationResult>(response).await
+    }
+
+    pub async fn count<'a, P>(&self, request_pattern: P) -> Result<u32>
+        where P: Into<Cow<'a, RequestPattern>>,
+    {
+        let verification_result = self.count_requests_matching(request_pattern).await?;
+        verification_result.check_request_journal_enabled()?;
+        verification_result.count().ok_or(Error::RequestJournalDisabled)
+    }
+
+    /// Finds the requests matching the pattern, applying an inline custom matcher client-side.
+    pub async fn find_requests_matching<'a, P>(&self, request_pattern: P) -> Result<FindRequestsResult>
+        where P: Into<Cow<'a, RequestPattern>>,
+    {
+        let request_pattern = request_pattern.into();
+        let response = self.send_json_request(Method::POST, "/requests/find", &request_pattern).await?;
+        let find_requests_result = read_json::<FindRequestsResult>(response).await?;
+        Ok(filter_inline_custom_matcher(&request_pattern, find_requests_result))
+    }
+
+    pub async fn find<'a, P>(&self, request_pattern: P) -> Result<Vec<LoggedRequest>>
+        where P: Into<Cow<'a, RequestPattern>>,
+    {
+        let find_requests_result = self.find_requests_matching(request_pattern).await?;
+        find_requests_result.check_request_journal_enabled()?;
+        Ok(find_requests_result.into())
+    }
+
+    pub async fn find_unmatched_requests(&self) -> Result<FindRequestsResult> {
+        let response = self.send_empty_request(Method::GET, "/requests/unmatched").await?;
+        read_json::<FindRequestsResult>(response).await
+    }
+
+    pub async fn find_unmatched(&self) -> Result<Vec<LoggedRequest>> {
+        let find_requests_result = self.find_unmatched_requests().await?;
+        find_requests_result.check_request_journal_enabled()?;
+        Ok(find_requests_result.into())
+    }
+
+    pub async fn find_top_near_misses_for_request(&self, logged_request: &LoggedRequest) -> Result<FindNearMissesResult> {
+        let response = self.send_json_request(Method::POST, "/near-misses/request", logged_request).await?;
+        read_json::<FindNearMissesResult>(response).await
+    }
+
+    pub async fn find_near_misses_for_request(&self, logged_request: &LoggedRequest) -> Result<Vec<NearMiss>> {
+        self.find_top_near_misses_for_request(logged_request).await
+            .map(FindNearMissesResult::into)
+    }
+
+    pub async fn find_top_near_misses_for<'a, P>(&self, request_pattern: P) -> Result<FindNearMissesResult>
+        where P: Into<Cow<'a, RequestPattern>>,
+    {
+        let response = self.send_json_request(Method::POST, "/near-misses/request-pattern", &request_pattern.into()).await?;
+        read_json::<FindNearMissesResult>(response).await
+    }
+
+    pub async fn find_near_misses_for<'a, P>(&self, request_pattern: P) -> Result<Vec<NearMiss>>
+        where P: Into<Cow<'a, RequestPattern>>,
+    {
+        self.find_top_near_misses_for(request_pattern).await
+            .map(FindNearMissesResult::into)
+    }
+
+    pub async fn find_top_near_misses_for_unmatched_requests(&self) -> Result<FindNearMissesResult> {
+        let response = self.send_empty_request(Method::GET, "/requests/unmatched/near-misses").await?;
+        read_json::<FindNearMissesResult>(response).await
+    }
+
+    pub async fn find_near_misses_for_unmatched_requests(&self) -> Result<Vec<NearMiss>> {
+        self.find_top_near_misses_for_unmatched_requests().await
+            .map(FindNearMissesResult::into)
+    }
+
+    pub async fn get_all_scenarios(&self) -> Result<GetScenariosResult> {
+        let response = self.send_empty_request(Method::GET, "/scenarios").await?;
+        read_json::<GetScenariosResult>(response).await
+    }
+
+    pub async fn get_scenarios(&self) -> Result<Vec<Scenario>> {
+        self.get_all_scenarios().await
+            .map(GetScenariosResult::into)
+    }
+
+    pub async fn shutdown_server(&self) -> Result<()> {
+        self.send_empty_request(Method::POST, "/shutdown").await
+            .map(|_| ())
+    }
+
+    pub async fn find_all_stubs_by_metadata<P>(&self, pattern: P) -> Result<ListStubMappingsResult>
+        where P: StringValuePattern + Sized,
+    {
+        let content_pattern: ContentPattern = pattern.into();
+        let response = self.send_json_request(Method::POST, "/mappings/find-by-metadata", &content_pattern).await?;
+        read_json::<ListStubMappingsResult>(response).await
+    }
+
+    pub async fn find_stubs_by_metadata<P>(&self, pattern: P) -> Result<Vec<StubMapping>>
+        where P: StringValuePattern + Sized,
+    {
+        self.find_all_stubs_by_metadata(pattern).await
+            .map(ListStubMappingsResult::into)
+    }
+
+    pub async fn remove_stubs_by_metadata<P>(&self, pattern: P) -> Result<()>
+        where P: StringValuePattern + Sized,
+    {
+        let content_pattern: ContentPattern = pattern.into();
+        self.send_json_request(Method::POST, "/mappings/remove-by-metadata", &content_pattern).await
+            .map(|_| ())
+    }
+
+    pub async fn import_stubs<I>(&self, stub_import: I) -> Result<Vec<StubMapping>>
+        where I: Into<StubImport>,
+    {
+        let stub_import = stub_import.into();
+        self.send_json_request(Method::POST, "/mappings/import", &stub_import).await
+            .map(|_| stub_import.into())
+    }
+
+    /// Imports the stub mappings loaded by [`load_mappings_dir`](../stubbing/fn.load_mappings_dir.html).
+    pub async fn import_stubs_from_dir<P>(&self, path: P, duplicate_policy: StubImportDuplicatePolicy) -> Result<Vec<StubMapping>>
+        where P: AsRef<Path>,
+    {
+        let path = path.as_ref().to_path_buf();
+        let stub_import = spawn_blocking(move || stub_import_from_dir(&path, duplicate_policy)).await?;
+        self.import_stubs(stub_import).await
+    }
+
+    /// Writes the current stub mappings with [`write_mappings_dir`](../stubbing/fn.write_mappings_dir.html).
+    pub async fn export_mappings_to_dir<P>(&self, path: P, options: MappingsExportOptions) -> Result<Vec<PathBuf>>
+        where P: AsRef<Path>,
+    {
+        let path = path.as_ref().to_path_buf();
+        let stub_mappings = self.list_stub_mappings().await?;
+        spawn_blocking(move || write_mappings_dir(path, stub_mappings, options)).await
+    }
+
+    /// Fails with `Error::UnsupportedInlineCustomMatcher` if the request filter has an inline custom matcher.
+    pub async fn start_recording<S>(&self, record_spec: S) -> Result<()>
+        where S: Into<RecordSpec>,
+    {
+        let record_spec = record_spec.into();
+        if record_spec.has_inline_custom_matcher() {
+            return Err(Error::UnsupportedInlineCustomMatcher);
+        }
+        self.send_json_request(Method::POST, "/recordings/start", &record_spec).await
+            .map(|_| ())
+    }
+
+    pub async fn stop_recording_result(&self) -> Result<SnapshotRecordResult> {
+        let response = self.send_empty_request(Method::POST, "/recordings/stop").await?;
+        read_json::<SnapshotRecordResult>(response).await
+    }
+
+    pub async fn stop_recording(&self) -> Result<Vec<StubMapping>> {
+        self.stop_recording_result().await
+            .map(SnapshotRecordResult::into)
+    }
+
+    pub async fn get_recording_status_result(&self) -> Result<RecordingStatusResult> {
+        let response = self.send_empty_request(Method::GET, "/recordings/status").await?;
+        read_json::<RecordingStatusResult>(response).await
+    }
+
+    pub async fn get_recording_status(&self) -> Result<RecordingStatus> {
+        self.get_recording_status_result().await
+            .map(RecordingStatusResult::into)
+    }
+
+    /// Fails with `Error::UnsupportedInlineCustomMatcher` if the request filter has an inline custom matcher.
+    pub async fn snapshot_record_result<S>(&self, record_spec: S) -> Result<SnapshotRecordResult>
+        where S: Into<RecordSpec>,
+    {
+        let record_spec = record_spec.into();
+        if record_spec.has_inline_custom_matcher() {
+            return Err(Error::UnsupportedInlineCustomMatcher);
+        }
+        let response = self.send_json_request(Method::POST, "/recordings/snapshot", &record_spec).await?;
+        read_json::<SnapshotRecordResult>(response).await
+    }
+
+    pub async fn snapshot_record<S>(&self, record_spec: S) -> Result<Vec<StubMapping>>
+        where S: Into<RecordSpec>,
+    {
+        self.snapshot_record_result(record_spec).await
+            .map(SnapshotRecordResult::into)
+    }
+
+    pub async fn set_global_fixed_delay(&self, milliseconds: u16) -> Result<()> {
+        let settings = self.get_global_settings().await?
+            .clone_to_builder()
+            .fixed_delay(Some(milliseconds))
+            .build();
+        self.update_global_settings(&settings).await
+    }
+
+    pub async fn set_global_random_delay(&self, distribution: DelayDistribution) -> Result<()> {
+        let settings = self.get_global_settings().await?
+            .clone_to_builder()
+            .delay_distribution(Some(distribution))
+            .build();
+        self.update_global_settings(&settings).await
+    }
+
+    pub async fn update_global_settings(&self, global_settings: &GlobalSettings) -> Result<()> {
+        self.send_json_request(Method::POST, "/settings", global_settings).await
+            .map(|_| ())
+    }
+
+    pub async fn get_global_settings_result(&self) -> Result<GetGlobalSettingsResult> {
+        let response = self.send_empty_request(Method::GET, "/settings").await?;
+        read_json::<GetGlobalSettingsResult>(response).await
+    }
+
+    pub async fn get_global_settings(&self) -> Result<GlobalSettings> {
+        self.get_global_settings_result().await
+            .map(GetGlobalSettingsResult::into)
+    }
+
+    /// Lists the names of the body files (relative to the `__files` directory).
+    pub async fn list_files(&self) -> Result<Vec<String>> {
+        let response = self.send_empty_request(Method::GET, "/files").await?;
+        read_json::<Vec<String>>(response).await
+    }
+
+    pub async fn get_file(&self, name: &str) -> Result<Option<Vec<u8>>> {
+        let response = match self.send_empty_request(Method::GET, &file_path(name)).await {
+            Ok(response) => response,
+            Err(error) => return map_not_found_error_to_none(error),
+        };
+        let bytes = response.bytes().await?;
+        Ok(Some(bytes.to_vec()))
+    }
+
+    /// Creates or replaces the body file.
+    pub async fn put_file<B>(&self, name: &str, content: B) -> Result<()>
+        where B: Into<Vec<u8>>,
+    {
+        self.send_bytes_request(Method::PUT, &file_path(name), content.into()).await
+            .map(|_| ())
+    }
+
+    /// Deletes the body file and returns whether it existed.
+    pub async fn delete_file(&self, name: &str) -> Result<bool> {
+        self.send_empty_request(Method::DELETE, &file_path(name)).await
+            .map(|_| true)
+            .or_else(|error| map_not_found_error_to(error, false))
+    }
+
+    /// Uploads the body file and returns a response with it as body.
+    pub async fn upload_body_file_and_stub<B>(&self, name: &str, content: B) -> Result<ResponseDefinitionBuilder>
+        where B: Into<Vec<u8>>,
+    {
+        self.put_file(name, content).await?;
+        Ok(a_response().with_body_file(name))
+    }
+
+    async fn send_empty_request(&self, method: Method, path: &str) -> Result<Response> {
+        let request = self.create_request(method, path);
+
+        let response = request.send().await?;
+        check_status(response).await
+    }
+
+    async fn send_query_request<Q>(&self, method: Method, path: &str, query: &Q) -> Result<Response>
+        where Q: Serialize + ?Sized
+    {
+        let request = self.create_request(method, path);
+
+        let response = request.query(query).send().await?;
+        check_status(response).await
+    }
+
+    async fn send_json_request<T>(&self, method: Method, path: &str, json: &T) -> Result<Response>
+        where T: Serialize + ?Sized
+    {
+        let request = self.create_request(method, path);
+        let body = serde_json::to_vec(json)?;
+
+        let response = request.header(http::header::CONTENT_TYPE.as_str(), "application/json")
+            .body(body)
+            .send().await?;
+        check_status(response).await
+    }
+
+    async fn send_bytes_request(&self, method: Method, path: &str, bytes: Vec<u8>) -> Result<Response> {
+        let request = self.create_request(method, path);
+
+        let response = request.header(http::header::CONTENT_TYPE.as_str(), "application/octet-stream")
+            .body(bytes)
+            .send().await?;
+        check_status(response).await
+    }
+
+    fn create_request(&self, method: Method, path: &str) -> RequestBuilder {
+        let url = format!("{}://{}:{}{}/__admin{}",
+                          self.scheme, self.host, self.port, self.url_path_prefix, path);
+
+        let mut request = self.client.request(method, &url);
+
+        // The async client is built on a newer version of the http crate,
+        // so headers are handed over as raw names and bytes.
+        if let Some(host_header) = self.host_header.as_ref() {
+            request = request.header(http::header::HOST.as_str(), host_header.as_bytes());
+        };
+
+        for (header_name, header_value) in self.authenticator.generate_auth_headers().iter() {
+            request = request.header(header_name.as_str(), header_value.as_bytes());
+        };
+
+        request
+    }
+}
+
+async fn check_status(response: Response) -> Result<Response> {
+    if let Some(status) = error_status(response.status().as_u16()) {
+        let body = response.text().await.unwrap_or_default();
+        return Err(Error::Http { status, body });
+    }
+
+    Ok(response)
+}
+
+async fn read_json<T>(response: Response) -> Result<T>
+    where T: DeserializeOwned,
+{
+    parse_json(&response.text().await?)
+}
+
+/// Runs blocking file system work without blocking the executor thread.
+async fn spawn_blocking<F, T>(f: F) -> Result<T>
+    where F: FnOnce() -> Result<T> + Send + 'static,
+          T: Send + 'static,
+{
+    match tokio::task::spawn_blocking(f).await {
+        Ok(result) => result,
+        Err(error) => panic::resume_unwind(error.into_panic()),
+    }
+}
@@ -2,9 +2,9 @@ use uuid::Uuid;
 use indexmap::IndexMap;
 
 use crate::common::Metadata;
-use crate::extension::Parameters;
+use crate::extension::{Parameters, WebhookDefinition, WEBHOOK};
 use crate::http::{ResponseDefinition, RequestMethod};
-use crate::matching::{ContentPattern, StringValuePattern, UrlPattern, RequestPatternBuilder};
+use crate::matching::{ContentPattern, StringValuePattern, UrlPattern, RequestPatternBuilder, MultipartValuePatternBuilder};
 use crate::stubbing::StubMapping;
 use crate::client::BasicCredentials;
 
@@ -20,6 +20,14 @@ pub struct MappingBuilder {
 }
 
 impl MappingBuilder {
+    pub(crate) fn for_custom_matcher<S, P>(custom_request_matcher_name: S, parameters: P) -> MappingBuilder
+        where S: Into<String>,
+              P: Into<Parameters>,
+    {
+        MappingBuilder::with_request_pattern_builder(
+            RequestPatternBuilder::for_custom_matcher(custom_request_matcher_name, parameters))
+    }
+
     pub(crate) fn new(request_method: RequestMethod, url_pattern: UrlPattern) -> MappingBuilder {
         MappingBuilder {
             request_pattern_builder: RequestPatternBuilder::new(request_method, url_pattern),
@@ -79,7 +87,12 @@ impl MappingBuilder {
         self
     }
 
-    // fn with_multipart_request_body(mut self, MultipartValuePatternBuilder multipartPatternBuilder) -> MappingBuilder;
+    pub fn with_multipart_request_body(mut self, multipart_pattern_builder: MultipartValuePatternBuilder)
+                                       -> MappingBuilder {
+        self.do_with_request_pattern_builder(|builder|
+            builder.with_request_body_part(multipart_pattern_builder.build()));
+        self
+    }
 
     pub fn in_scenario<S>(self, scenario_name: S) -> ScenarioMappingBuilder
         where S: Into<String>,
@@ -138,6 +151,13 @@ impl MappingBuilder {
         self
     }
 
+    /// Sends the webhook after serving the stub (replaces a previously attached webhook).
+    pub fn with_webhook<W>(self, webhook: W) -> MappingBuilder
+        where W: Into<WebhookDefinition>,
+    {
+        self.with_post_serve_action(WEBHOOK, webhook.into())
+    }
+
     pub fn with_metadata<M>(mut self, metadata: M) -> MappingBuilder
         where M: Into<Metadata>,
     {
@@ -146,8 +166,16 @@ impl MappingBuilder {
     }
 
 //    fn and_matching(mut self, ValueMatcher<Request> requestMatcher) -> MappingBuilder;
-//    fn and_matching(mut self, String customRequestMatcherName) -> MappingBuilder;
-//    fn and_matching(mut self, String customRequestMatcherName, Parameters parameters) -> MappingBuilder;
+
+    /// Additionally matches with the request matcher extension of the name registered in WireMock.
+    pub fn and_matching<S, P>(mut self, custom_request_matcher_name: S, parameters: P) -> MappingBuilder
+        where S: Into<String>,
+              P: Into<Parameters>,
+    {
+        self.do_with_request_pattern_builder(|builder|
+            builder.and_matching(custom_request_matcher_name, parameters));
+        self
+    }
 
     pub fn will_return<R>(mut self, response_definition: R) -> MappingBuilder
         where R: Into<ResponseDefinition>,
@@ -159,6 +187,7 @@ impl MappingBuilder {
     pub fn build(self) -> StubMapping {
         StubMapping {
             id: self.id,
+            uuid: None,
             name: self.name,
             request: self.request_pattern_builder.build(),
             response: self.response_definition
@@ -239,7 +268,12 @@ impl ScenarioMappingBuilder {
         self
     }
 
-    // fn with_multipart_request_body(mut self, MultipartValuePatternBuilder multipartPatternBuilder) -> ScenarioMappingBuilder;
+    pub fn with_multipart_request_body(mut self, multipart_pattern_builder: MultipartValuePatternBuilder)
+                                       -> ScenarioMappingBuilder {
+        self.do_with_mapping_builder(|builder|
+            builder.with_multipart_request_body(multipart_pattern_builder));
+        self
+    }
 
     pub fn in_scenario<S>(mut self, scenario_name: S) -> ScenarioMappingBuilder
         where S: Into<String>,
@@ -294,6 +328,14 @@ impl ScenarioMappingBuilder {
         self
     }
 
+    pub fn with_webhook<W>(mut self, webhook: W) -> ScenarioMappingBuilder
+        where W: Into<WebhookDefinition>,
+    {
+        self.do_with_mapping_builder(|builder|
+            builder.with_webhook(webhook));
+        self
+    }
+
     pub fn with_metadata<M>(mut self, metadata: M) -> ScenarioMappingBuilder
         where M: Into<Metadata>,
     {
@@ -303,8 +345,16 @@ impl ScenarioMappingBuilder {
     }
 
 //    fn and_matching(mut self, ValueMatcher<Request> requestMatcher) -> ScenarioMappingBuilder;
-//    fn and_matching(mut self, String customRequestMatcherName) -> ScenarioMappingBuilder;
-//    fn and_matching(mut self, String customRequestMatcherName, Parameters parameters) -> ScenarioMappingBuilder;
+
+    /// Additionally matches with the request matcher extension of the name registered in WireMock.
+    pub fn and_matching<S, P>(mut self, custom_request_matcher_name: S, parameters: P) -> ScenarioMappingBuilder
+        where S: Into<String>,
+              P: Into<Parameters>,
+    {
+        self.do_with_mapping_builder(|builder|
+            builder.and_matching(custom_request_matcher_name, parameters));
+        self
+    }
 
     pub fn will_return<R>(mut self, response_definition: R) -> ScenarioMappingBuilder
         where R: Into<ResponseDefinition>,
@@ -1,7 +1,9 @@
 pub use crate::client::builder::wire_mock_builder::*;
 pub use crate::client::builder::mapping_builder::*;
 pub use crate::client::builder::response_definition_builder::*;
+pub use crate::client::builder::webhook_definition_builder::*;
 
 mod wire_mock_builder;
 mod mapping_builder;
 mod response_definition_builder;
+mod webhook_definition_builder;
@@ -1,7 +1,8 @@
 use http::{HttpTryFrom, StatusCode};
 use http::header::{HeaderMap, HeaderName, HeaderValue};
+use serde::Serialize;
 
-use crate::extension::Parameters;
+use crate::extension::{Parameters, RESPONSE_TEMPLATE};
 use crate::http::{Body, DelayDistribution, Fault, ResponseDefinition, ChunkedDribbleDelay};
 
 pub struct ResponseDefinitionBuilder {
@@ -16,7 +17,6 @@ pub struct ResponseDefinitionBuilder {
     fault: Option<Fault>,
     transformers: Vec<String>,
     transformer_parameters: Parameters,
-    from_configured_stub: bool,
 }
 
 impl ResponseDefinitionBuilder {
@@ -33,7 +33,6 @@ impl ResponseDefinitionBuilder {
             fault: None,
             transformers: Vec::new(),
             transformer_parameters: Parameters::empty(),
-            from_configured_stub: true,
         }
     }
 
@@ -72,7 +71,7 @@ impl ResponseDefinitionBuilder {
         self
     }
 
-    pub fn with_headers<'a, H>(mut self, headers: H) -> ResponseDefinitionBuilder
+    pub fn with_headers<H>(mut self, headers: H) -> ResponseDefinitionBuilder
         where H: Into<HeaderMap>,
     {
         self.headers = headers.into();
@@ -93,6 +92,24 @@ impl ResponseDefinitionBuilder {
         self
     }
 
+    /// Sets the serialized value as JSON response body (panics if it cannot be serialized).
+    pub fn with_json_body<T>(self, body: T) -> ResponseDefinitionBuilder
+        where T: Serialize,
+    {
+        match self.try_with_json_body(body) {
+            Ok(builder) => builder,
+            Err(error) => panic!("body cannot be serialized to JSON: {}", error),
+        }
+    }
+
+    /// Like [`with_json_body`](#method.with_json_body), but fails if the value cannot be serialized.
+    pub fn try_with_json_body<T>(mut self, body: T) -> serde_json::Result<ResponseDefinitionBuilder>
+        where T: Serialize,
+    {
+        self.body = Some(Body::Json(serde_json::to_value(body)?));
+        Ok(self)
+    }
+
     pub fn with_fixed_delay(mut self, milliseconds: u32) -> ResponseDefinitionBuilder {
         self.fixed_delay_milliseconds = Some(milliseconds);
         self
@@ -129,6 +146,34 @@ impl ResponseDefinitionBuilder {
         self
     }
 
+    /// Renders the body, headers and proxy URL with WireMock's response templating.
+    ///
+    /// The status cannot be templated: WireMock only renders these parts and always responds with
+    /// the fixed `status`. Responses with different statuses need separate stubs.
+    pub fn with_templating(mut self) -> ResponseDefinitionBuilder {
+        if !self.transformers.iter().any(|transformer| transformer == RESPONSE_TEMPLATE) {
+            self.transformers.push(RESPONSE_TEMPLATE.to_string());
+        }
+        self
+    }
+
+    /// Adds a header whose value is rendered by response templating (which is enabled).
+    pub fn with_templated_header<K, V>(self, key: K, template: V) -> ResponseDefinitionBuilder
+        where K: AsRef<str>,
+              V: AsRef<str>,
+    {
+        self.with_templating()
+            .with_header(key, template)
+    }
+
+    /// Sets a body that is rendered by response templating (which is enabled).
+    pub fn with_templated_body<S>(self, template: S) -> ResponseDefinitionBuilder
+        where S: Into<String>,
+    {
+        self.with_templating()
+            .with_body(Body::String(template.into()))
+    }
+
     pub fn with_transformers<I>(mut self, response_transformer_names: I) -> ResponseDefinitionBuilder
         where I: IntoIterator<Item=String>,
     {
@@ -187,7 +232,7 @@ impl ResponseDefinitionBuilder {
             fault: self.fault,
             transformers: self.transformers,
             transformer_parameters: self.transformer_parameters,
-            from_configured_stub: self.from_configured_stub,
+            from_configured_stub: None,
         }
     }
 }
@@ -222,7 +267,7 @@ impl ProxyResponseDefinitionBuilder {
         self
     }
 
-    pub fn with_additional_request_headers<'a, H>(mut self, headers: H) -> ProxyResponseDefinitionBuilder
+    pub fn with_additional_request_headers<H>(mut self, headers: H) -> ProxyResponseDefinitionBuilder
         where H: Into<HeaderMap>,
     {
         self.additional_request_headers = headers.into();
@@ -246,7 +291,7 @@ impl ProxyResponseDefinitionBuilder {
         self
     }
 
-    pub fn with_headers<'a, H>(mut self, headers: H) -> ProxyResponseDefinitionBuilder
+    pub fn with_headers<H>(mut self, headers: H) -> ProxyResponseDefinitionBuilder
         where H: Into<HeaderMap>,
     {
         self.do_with_response_definition_builder(|builder|
@@ -308,6 +353,29 @@ impl ProxyResponseDefinitionBuilder {
         self
     }
 
+    pub fn with_templating(mut self) -> ProxyResponseDefinitionBuilder {
+        self.do_with_response_definition_builder(|builder|
+            builder.with_templating());
+        self
+    }
+
+    pub fn with_templated_header<K, V>(mut self, key: K, template: V) -> ProxyResponseDefinitionBuilder
+        where K: AsRef<str>,
+              V: AsRef<str>,
+    {
+        self.do_with_response_definition_builder(|builder|
+            builder.with_templated_header(key, template));
+        self
+    }
+
+    pub fn with_templated_body<S>(mut self, template: S) -> ProxyResponseDefinitionBuilder
+        where S: Into<String>,
+    {
+        self.do_with_response_definition_builder(|builder|
+            builder.with_templated_body(template));
+        self
+    }
+
     pub fn with_transformers<I>(mut self, response_transformer_names: I) -> ProxyResponseDefinitionBuilder
         where I: IntoIterator<Item=String>,
     {
@@ -361,7 +429,7 @@ impl ProxyResponseDefinitionBuilder {
             fault: self.response_definition_builder.fault,
             transformers: self.response_definition_builder.transformers,
             transformer_parameters: self.response_definition_builder.transformer_parameters,
-            from_configured_stub: self.response_definition_builder.from_configured_stub,
+            from_configured_stub: None,
         }
     }
 
@@ -0,0 +1,89 @@
+use http::header::{HeaderMap, HeaderName, HeaderValue};
+use http::HttpTryFrom;
+
+use crate::extension::WebhookDefinition;
+use crate::http::{DelayDistribution, RequestMethod};
+
+pub struct WebhookDefinitionBuilder {
+    method: Option<RequestMethod>,
+    url: Option<String>,
+    headers: HeaderMap,
+    body: Option<String>,
+    delay: Option<DelayDistribution>,
+}
+
+impl WebhookDefinitionBuilder {
+    pub(crate) fn new() -> WebhookDefinitionBuilder {
+        WebhookDefinitionBuilder {
+            method: None,
+            url: None,
+            headers: HeaderMap::new(),
+            body: None,
+            delay: None,
+        }
+    }
+
+    pub fn with_method<M>(mut self, method: M) -> WebhookDefinitionBuilder
+        where M: Into<RequestMethod>,
+    {
+        self.method = Some(method.into());
+        self
+    }
+
+    pub fn with_url<S>(mut self, url: S) -> WebhookDefinitionBuilder
+        where S: Into<String>,
+    {
+        self.url = Some(url.into());
+        self
+    }
+
+    pub fn with_header<K, V>(mut self, key: K, value: V) -> WebhookDefinitionBuilder
+        where K: AsRef<str>,
+              V: AsRef<str>,
+    {
+        let key_ref = key.as_ref();
+        let header_name = match HeaderName::try_from(key_ref) {
+            Ok(name) => name,
+            Err(_invalid_header_name_error) => {
+                panic!("header name \"{}\" is invalid", key_ref);
+            }
+        };
+
+        let value_ref = value.as_ref();
+        let header_value = match HeaderValue::from_str(value_ref) {
+            Ok(name) => name,
+            Err(_invalid_header_value_error) => {
+                panic!("header value \"{}\" is invalid", value_ref);
+            }
+        };
+
+        self.headers.append(header_name, header_value);
+        self
+    }
+
+    pub fn with_body<S>(mut self, body: S) -> WebhookDefinitionBuilder
+        where S: Into<String>,
+    {
+        self.body = Some(body.into());
+        self
+    }
+
+    pub fn with_fixed_delay(self, milliseconds: u32) -> WebhookDefinitionBuilder {
+        self.with_random_delay(DelayDistribution::Fixed { milliseconds })
+    }
+
+    pub fn with_random_delay(mut self, distribution: DelayDistribution) -> WebhookDefinitionBuilder {
+        self.delay = Some(distribution);
+        self
+    }
+
+    pub fn build(self) -> WebhookDefinition {
+        WebhookDefinition {
+            method: self.method,
+            url: self.url,
+            headers: self.headers,
+            body: self.body,
+            delay: self.delay,
+        }
+    }
+}
@@ -1,15 +1,26 @@
 use http::HeaderValue;
 
+#[cfg(feature = "async")]
+use crate::client::AsyncWireMock;
 use crate::client::WireMock;
 use crate::security::{ClientAuthenticator, NoClientAuthenticator};
 
-pub struct WireMockBuilder {
+/// Builds a [`WireMock`](../struct.WireMock.html) client.
+///
+/// Only [`build_async`](#method.build_async) requires the authenticator to be `Send + Sync`.
+pub struct WireMockBuilder<A = NoClientAuthenticator> {
     scheme: String,
     host: String,
     port: u16,
     url_path_prefix: String,
     host_header: Option<HeaderValue>,
-    authenticator: Box<dyn ClientAuthenticator>,
+    authenticator: A,
+}
+
+impl Default for WireMockBuilder {
+    fn default() -> WireMockBuilder {
+        WireMockBuilder::new()
+    }
 }
 
 impl WireMockBuilder {
@@ -20,43 +31,56 @@ impl WireMockBuilder {
             port: 8080,
             url_path_prefix: String::new(),
             host_header: None,
-            authenticator: Box::new(NoClientAuthenticator),
+            authenticator: NoClientAuthenticator,
         }
     }
+}
 
-    pub fn scheme<S: Into<String>>(mut self, scheme: S) -> WireMockBuilder {
+impl<A: ClientAuthenticator + 'static> WireMockBuilder<A> {
+    pub fn scheme<S: Into<String>>(mut self, scheme: S) -> WireMockBuilder<A> {
         self.scheme = scheme.into();
         self
     }
 
-    pub fn http(self) -> WireMockBuilder {
+    pub fn http(self) -> WireMockBuilder<A> {
         self.scheme("http")
     }
 
-    pub fn https(self) -> WireMockBuilder {
+    pub fn https(self) -> WireMockBuilder<A> {
         self.scheme("https")
     }
 
-    pub fn host<S: Into<String>>(mut self, host: S) -> WireMockBuilder {
+    pub fn host<S: Into<String>>(mut self, host: S) -> WireMockBuilder<A> {
         self.host = host.into();
         self
     }
 
-    pub fn port(mut self, port: u16) -> WireMockBuilder {
+    pub fn port(mut self, port: u16) -> WireMockBuilder<A> {
         self.port = port;
         self
     }
 
-    pub fn url_path_prefix<S: Into<String>>(mut self, url_path_prefix: S) -> WireMockBuilder {
+    pub fn url_path_prefix<S: Into<String>>(mut self, url_path_prefix: S) -> WireMockBuilder<A> {
         self.url_path_prefix = url_path_prefix.into();
         self
     }
 
-    pub fn host_header<S: Into<String>>(mut self, host_header: S) -> WireMockBuilder {
+    pub fn host_header<S: Into<String>>(mut self, host_header: S) -> WireMockBuilder<A> {
         self.host_header = Some(host_header.into().parse().unwrap());
         self
     }
 
+    pub fn authenticator<B: ClientAuthenticator + 'static>(self, authenticator: B) -> WireMockBuilder<B> {
+        WireMockBuilder {
+            scheme: self.scheme,
+            host: self.host,
+            port: self.port,
+            url_path_prefix: self.url_path_prefix,
+            host_header: self.host_header,
+            authenticator,
+        }
+    }
+
     pub fn build(self) -> WireMock {
         WireMock {
             client: reqwest::Client::new(),
@@ -65,7 +89,21 @@ impl WireMockBuilder {
             port: self.port,
             url_path_prefix: self.url_path_prefix,
             host_header: self.host_header,
-            authenticator: self.authenticator,
+            authenticator: Box::new(self.authenticator),
         }
     }
+
+    #[cfg(feature = "async")]
+    pub fn build_async(self) -> AsyncWireMock
+        where A: Send + Sync,
+    {
+        AsyncWireMock::new(
+            self.scheme,
+            self.host,
+            self.port,
+            self.url_path_prefix,
+            self.host_header,
+            Box::new(self.authenticator),
+        )
+    }
 }
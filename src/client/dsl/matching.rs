@@ -1,3 +1,5 @@
+use serde::Serialize;
+
 use crate::matching::*;
 
 pub fn equal_to<S>(value: S) -> EqualToPattern
@@ -24,12 +26,37 @@ pub fn equal_to_json<S>(json: S) -> EqualToJsonPattern
     EqualToJsonPattern::new(json)
 }
 
+/// Matches JSON equal to the serialized value (panics if it cannot be serialized).
+pub fn equal_to_json_value<T>(value: T) -> EqualToJsonPattern
+    where T: Serialize,
+{
+    match try_equal_to_json_value(value) {
+        Ok(pattern) => pattern,
+        Err(error) => panic!("value cannot be serialized to JSON: {}", error),
+    }
+}
+
+/// Like [`equal_to_json_value`](fn.equal_to_json_value.html), but fails if the value cannot be serialized.
+pub fn try_equal_to_json_value<T>(value: T) -> serde_json::Result<EqualToJsonPattern>
+    where T: Serialize,
+{
+    serde_json::to_value(value).map(EqualToJsonPattern::from)
+}
+
 pub fn matching_json_path<S>(json_path: S) -> MatchesJsonPathPattern
     where S: Into<String>,
 {
     MatchesJsonPathPattern::new(json_path)
 }
 
+/// Matches the result of the JSONPath expression against the pattern.
+pub fn matching_json_path_with<S, P>(json_path: S, value_pattern: P) -> MatchesJsonPathPattern
+    where S: Into<String>,
+          P: StringValuePattern,
+{
+    MatchesJsonPathPattern::new(json_path).with_value_pattern(value_pattern)
+}
+
 pub fn equal_to_xml<S>(xml: S) -> EqualToXmlPattern
     where S: Into<String>,
 {
@@ -42,6 +69,14 @@ pub fn matching_xpath<S>(value: S) -> MatchesXPathPattern
     MatchesXPathPattern::new(value)
 }
 
+/// Matches the result of the XPath expression against the pattern.
+pub fn matching_xpath_with<S, P>(value: S, value_pattern: P) -> MatchesXPathPattern
+    where S: Into<String>,
+          P: StringValuePattern,
+{
+    MatchesXPathPattern::new(value).with_value_pattern(value_pattern)
+}
+
 pub fn containing<S>(value: S) -> ContainsPattern
     where S: Into<String>,
 {
@@ -63,3 +98,51 @@ pub fn not_matching<S>(regex: S) -> NegativeRegexPattern
 pub fn absent() -> AbsentPattern {
     AbsentPattern::new()
 }
+
+pub fn before<E>(expected: E) -> BeforeDateTimePattern
+    where E: Into<ExpectedDateTime>,
+{
+    BeforeDateTimePattern::new(expected)
+}
+
+pub fn before_now() -> BeforeDateTimePattern {
+    BeforeDateTimePattern::new(ExpectedDateTime::now())
+}
+
+pub fn equal_to_date_time<E>(expected: E) -> EqualToDateTimePattern
+    where E: Into<ExpectedDateTime>,
+{
+    EqualToDateTimePattern::new(expected)
+}
+
+pub fn is_now() -> EqualToDateTimePattern {
+    EqualToDateTimePattern::new(ExpectedDateTime::now())
+}
+
+pub fn after<E>(expected: E) -> AfterDateTimePattern
+    where E: Into<ExpectedDateTime>,
+{
+    AfterDateTimePattern::new(expected)
+}
+
+pub fn after_now() -> AfterDateTimePattern {
+    AfterDateTimePattern::new(ExpectedDateTime::now())
+}
+
+pub fn a_multipart() -> MultipartValuePatternBuilder {
+    MultipartValuePatternBuilder::new()
+}
+
+pub fn and<I>(patterns: I) -> LogicalAndPattern
+    where I: IntoIterator,
+          I::Item: Into<ContentPattern>,
+{
+    LogicalAndPattern::new(patterns)
+}
+
+pub fn or<I>(patterns: I) -> LogicalOrPattern
+    where I: IntoIterator,
+          I::Item: Into<ContentPattern>,
+{
+    LogicalOrPattern::new(patterns)
+}
@@ -4,6 +4,7 @@ pub use crate::client::dsl::request::*;
 pub use crate::client::dsl::response::*;
 pub use crate::client::dsl::stubbing::*;
 pub use crate::common::metadata;
+pub use crate::recording::record_spec;
 pub use crate::stubbing::stub_import;
 
 mod count;
@@ -1,3 +1,4 @@
+use crate::extension::Parameters;
 use crate::matching::{UrlPattern, RequestPatternBuilder};
 use crate::http::RequestMethod;
 
@@ -82,3 +83,18 @@ pub fn any_requested_for<P>(url_pattern: P) -> RequestPatternBuilder
 {
     RequestPatternBuilder::new(RequestMethod::ANY, url_pattern.into())
 }
+
+pub fn requested_for<M, P>(method: M, url_pattern: P) -> RequestPatternBuilder
+    where M: Into<RequestMethod>,
+          P: Into<UrlPattern>,
+{
+    RequestPatternBuilder::new(method.into(), url_pattern.into())
+}
+
+/// Matches requests with the request matcher extension of the name registered in WireMock.
+pub fn request_made_for<S, P>(custom_request_matcher_name: S, parameters: P) -> RequestPatternBuilder
+    where S: Into<String>,
+          P: Into<Parameters>,
+{
+    RequestPatternBuilder::for_custom_matcher(custom_request_matcher_name, parameters)
+}
@@ -22,7 +22,7 @@ pub fn ok_for_content_type<S, B>(content_type: S, body: B) -> ResponseDefinition
           B: Into<Body>,
 {
     ok()
-        .with_header(reqwest::header::CONTENT_TYPE.to_string(), content_type.into())
+        .with_header(&reqwest::header::CONTENT_TYPE, content_type.into())
         .with_body(body)
 }
 
@@ -56,21 +56,21 @@ pub fn permanent_redirect<S>(location: S) -> ResponseDefinitionBuilder
     where S: AsRef<str>,
 {
     status(StatusCode::PERMANENT_REDIRECT.as_u16())
-        .with_header(reqwest::header::LOCATION.to_string(), location)
+        .with_header(&reqwest::header::LOCATION, location)
 }
 
 pub fn temporary_redirect<S>(location: S) -> ResponseDefinitionBuilder
     where S: AsRef<str>,
 {
     status(StatusCode::TEMPORARY_REDIRECT.as_u16())
-        .with_header(reqwest::header::LOCATION.to_string(), location)
+        .with_header(&reqwest::header::LOCATION, location)
 }
 
 pub fn see_other<S>(location: S) -> ResponseDefinitionBuilder
     where S: AsRef<str>,
 {
     status(StatusCode::SEE_OTHER.as_u16())
-        .with_header(reqwest::header::LOCATION.to_string(), location)
+        .with_header(&reqwest::header::LOCATION, location)
 }
 
 pub fn bad_request() -> ResponseDefinitionBuilder {
@@ -1,4 +1,5 @@
-use crate::client::MappingBuilder;
+use crate::client::{MappingBuilder, WebhookDefinitionBuilder};
+use crate::extension::Parameters;
 use crate::matching::UrlPattern;
 use crate::http::RequestMethod;
 
@@ -63,14 +64,14 @@ pub fn request<M, P>(method: M, url_pattern: P) -> MappingBuilder
     MappingBuilder::new(method.into(), url_pattern.into())
 }
 
-//public static MappingBuilder requestMatching(String customRequestMatcherName) {
-//return new BasicMappingBuilder(customRequestMatcherName, Parameters.empty());
-//}
-//
-//public static MappingBuilder requestMatching(String customRequestMatcherName, Parameters parameters) {
-//return new BasicMappingBuilder(customRequestMatcherName, parameters);
-//}
-//
+/// Matches requests with the request matcher extension of the name registered in WireMock.
+pub fn request_matching<S, P>(custom_request_matcher_name: S, parameters: P) -> MappingBuilder
+    where S: Into<String>,
+          P: Into<Parameters>,
+{
+    MappingBuilder::for_custom_matcher(custom_request_matcher_name, parameters)
+}
+
 //public static MappingBuilder requestMatching(ValueMatcher<Request> requestMatcher) {
 //return new BasicMappingBuilder(requestMatcher);
 //}
@@ -80,3 +81,8 @@ pub fn proxy_all_to<S>(url: S) -> MappingBuilder
 {
     any(crate::any_url()).will_return(crate::a_response().proxied_from(url))
 }
+
+/// A webhook to attach to a stub with `MappingBuilder::with_webhook`.
+pub fn webhook() -> WebhookDefinitionBuilder {
+    WebhookDefinitionBuilder::new()
+}
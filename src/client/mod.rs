@@ -1,27 +1,37 @@
 use std::borrow::Cow;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
 
-use http::{Method, StatusCode};
-use http::HeaderValue;
+use http::{HeaderValue, Method, StatusCode};
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
 use reqwest::{RequestBuilder, Response};
+use serde::de::DeserializeOwned;
 use serde::Serialize;
 use uuid::Uuid;
 
+#[cfg(feature = "async")]
+pub use async_wire_mock::AsyncWireMock;
 pub use builder::*;
 pub use credentials::BasicCredentials;
 pub use dsl::*;
+pub use serve_event_pages::ServeEventPages;
 
-use crate::client::builder::MappingBuilder;
 use crate::global::GlobalSettings;
-use crate::http::{Error, Result, DelayDistribution};
+use crate::error::{Error, Result};
+use crate::http::DelayDistribution;
 use crate::matching::{RequestPattern, StringValuePattern, ContentPattern, CountMatchingStrategy, CountMatchingMode};
-use crate::model::{GetGlobalSettingsResult, GetScenariosResult, GetServeEventsResult, ListStubMappingsResult, SingleServedStubResult, SingleStubMappingResult};
+use crate::recording::{RecordingStatus, RecordingStatusResult, RecordSpec, SnapshotRecordResult};
+use crate::model::{GetGlobalSettingsResult, GetScenariosResult, GetServeEventsResult, ListStubMappingsResult, PageParams, SingleServedStubResult, SingleStubMappingResult};
 use crate::security::ClientAuthenticator;
-use crate::stubbing::{Scenario, ServeEvent, StubMapping, StubImport};
-use crate::verification::{FindNearMissesResult, FindRequestsResult, JournalBasedResult, LoggedRequest, NearMiss, VerificationResult};
+use crate::stubbing::{load_mappings_dir, write_mappings_dir, MappingsExportOptions, Scenario, ServeEvent, StubMapping, StubImport, StubImportDuplicatePolicy};
+use crate::verification::{FindNearMissesResult, FindRequestsResult, FindServeEventsResult, JournalBasedResult, LoggedRequest, NearMiss, VerificationError, VerificationResult};
 
+#[cfg(feature = "async")]
+mod async_wire_mock;
 pub(crate) mod builder;
 mod credentials;
 mod dsl;
+mod serve_event_pages;
 
 pub struct WireMock {
     client: reqwest::Client,
@@ -39,8 +49,8 @@ impl Default for WireMock {
     }
 }
 
-impl From<WireMockBuilder> for WireMock {
-    fn from(builder: WireMockBuilder) -> WireMock {
+impl<A: ClientAuthenticator + 'static> From<WireMockBuilder<A>> for WireMock {
+    fn from(builder: WireMockBuilder<A>) -> WireMock {
         builder.build()
     }
 }
@@ -93,7 +103,7 @@ impl WireMock {
 
     pub fn list_all_stub_mappings(&self) -> Result<ListStubMappingsResult> {
         self.send_empty_request(Method::GET, "/")
-            .and_then(|mut response| response.json::<ListStubMappingsResult>())
+            .and_then(read_json::<ListStubMappingsResult>)
     }
 
     pub fn list_stub_mappings(&self) -> Result<Vec<StubMapping>> {
@@ -103,7 +113,7 @@ impl WireMock {
 
     pub fn get_stub_mapping(&self, id: &Uuid) -> Result<Option<StubMapping>> {
         self.send_empty_request(Method::GET, &format!("/mappings/{}", id))
-            .and_then(|mut response| response.json::<SingleStubMappingResult>())
+            .and_then(read_json::<SingleStubMappingResult>)
             .map(|result| Some(result.into()))
             .or_else(map_not_found_error_to_none)
     }
@@ -128,6 +138,21 @@ impl WireMock {
             .map(|_| ())
     }
 
+    pub fn set_scenario_state(&self, scenario_name: &str, state: &str) -> Result<()> {
+        let path = scenario_state_path(scenario_name);
+        let scenario_state = ScenarioState { state };
+        self.send_json_request(Method::PUT, &path, &scenario_state)
+            .map(|_| ())
+            .map_err(|error| map_scenario_error(error, scenario_name, Some(state)))
+    }
+
+    pub fn reset_scenario(&self, scenario_name: &str) -> Result<()> {
+        let path = scenario_state_path(scenario_name);
+        self.send_empty_request(Method::PUT, &path)
+            .map(|_| ())
+            .map_err(|error| map_scenario_error(error, scenario_name, None))
+    }
+
     pub fn reset_mappings(&self) -> Result<()> {
         self.send_empty_request(Method::DELETE, "/mappings")
             .map(|_| ())
@@ -141,46 +166,118 @@ impl WireMock {
     pub fn verify<'a, P>(&self, request_pattern: P)
         where P: Into<Cow<'a, RequestPattern>>,
     {
-		self.verify_count(more_than_or_exactly(1), request_pattern);
-	}
+        self.verify_count(more_than_or_exactly(1), request_pattern);
+    }
 
     pub fn verify_count<'a, C, P>(&self, expected_count: C, request_pattern: P)
         where C: Into<CountMatchingStrategy>,
               P: Into<Cow<'a, RequestPattern>>,
+    {
+        if let Err(error) = self.try_verify_count(expected_count, request_pattern) {
+            panic!("{}", error);
+        }
+    }
+
+    pub fn try_verify<'a, P>(&self, request_pattern: P) -> Result<()>
+        where P: Into<Cow<'a, RequestPattern>>,
+    {
+        self.try_verify_count(more_than_or_exactly(1), request_pattern)
+    }
+
+    pub fn try_verify_count<'a, C, P>(&self, expected_count: C, request_pattern: P) -> Result<()>
+        where C: Into<CountMatchingStrategy>,
+              P: Into<Cow<'a, RequestPattern>>,
     {
         let expected_count = expected_count.into();
-		let request_pattern = request_pattern.into();
+        let request_pattern = request_pattern.into();
 
-		let actual_count = if request_pattern.has_inline_custom_matcher() {
-            unimplemented!("verify_count with inline custom matcher");
-        } else {
-            self.count(request_pattern.as_ref()).unwrap()
-        };
+        let actual_count = self.count(request_pattern.as_ref())?;
 
-        if !expected_count.is_match(actual_count) {
-            panic!("Expected {} requests matching the following pattern but received {}:\n{}",
-                expected_count, actual_count, request_pattern);
+        if expected_count.is_match(actual_count) {
+            return Ok(());
         }
-	}
+
+        // near misses are only a diagnostic and must not hide the count mismatch
+        let near_misses = self.find_near_misses_for(request_pattern.as_ref()).unwrap_or_default();
+        Err(VerificationError::new(expected_count, actual_count, request_pattern.into_owned(), near_misses).into())
+    }
 
     pub fn get_serve_events(&self) -> Result<Vec<ServeEvent>> {
         self.send_empty_request(Method::GET, "/requests")
-            .and_then(|mut response| response.json::<GetServeEventsResult>())
+            .and_then(read_json::<GetServeEventsResult>)
             .map(GetServeEventsResult::into)
     }
 
+    pub fn get_serve_events_page(&self, page_params: PageParams) -> Result<GetServeEventsResult> {
+        self.send_query_request(Method::GET, "/requests", &page_params)
+            .and_then(read_json::<GetServeEventsResult>)
+    }
+
+    pub fn get_serve_events_since(&self, since: SystemTime) -> Result<Vec<ServeEvent>> {
+        self.get_serve_events_page(PageParams::default().with_since(since))
+            .map(GetServeEventsResult::into)
+    }
+
+    /// Walks the request journal with pages of the given limit (and optionally since).
+    pub fn serve_events_pages(&self, page_params: PageParams) -> ServeEventPages<'_> {
+        ServeEventPages::new(self, page_params)
+    }
+
     pub fn get_served_stub(&self, id: &Uuid) -> Result<Option<ServeEvent>> {
         self.send_empty_request(Method::GET, &format!("/requests/{}", id))
-            .and_then(|mut response| response.json::<SingleServedStubResult>())
+            .and_then(read_json::<SingleServedStubResult>)
             .map(|result| Some(result.into()))
             .or_else(map_not_found_error_to_none)
     }
 
+    pub fn remove_serve_event(&self, id: &Uuid) -> Result<Option<ServeEvent>> {
+        let serve_event = match self.get_served_stub(id)? {
+            Some(serve_event) => serve_event,
+            None => return Ok(None),
+        };
+        self.send_empty_request(Method::DELETE, &format!("/requests/{}", id))
+            .map(|_| Some(serve_event))
+            .or_else(map_not_found_error_to_none)
+    }
+
+    /// Fails with `Error::UnsupportedInlineCustomMatcher` if the pattern has an inline custom matcher.
+    pub fn remove_serve_events_matching<'a, P>(&self, request_pattern: P) -> Result<Vec<ServeEvent>>
+        where P: Into<Cow<'a, RequestPattern>>,
+    {
+        let request_pattern = request_pattern.into();
+        if request_pattern.has_inline_custom_matcher() {
+            return Err(Error::UnsupportedInlineCustomMatcher);
+        }
+        self.send_json_request(Method::POST, "/requests/remove", &request_pattern)
+            .and_then(read_json::<FindServeEventsResult>)
+            .and_then(|find_serve_events_result| {
+                find_serve_events_result.check_request_journal_enabled()?;
+                Ok(find_serve_events_result.into())
+            })
+    }
+
+    pub fn remove_serve_events_for_stubs_by_metadata<P>(&self, pattern: P) -> Result<Vec<ServeEvent>>
+        where P: StringValuePattern + Sized,
+    {
+        let content_pattern: ContentPattern = pattern.into();
+        self.send_json_request(Method::POST, "/requests/remove-by-metadata", &content_pattern)
+            .and_then(read_json::<FindServeEventsResult>)
+            .and_then(|find_serve_events_result| {
+                find_serve_events_result.check_request_journal_enabled()?;
+                Ok(find_serve_events_result.into())
+            })
+    }
+
+    /// Counts the requests matching the pattern, applying an inline custom matcher client-side.
     pub fn count_requests_matching<'a, P>(&self, request_pattern: P) -> Result<VerificationResult>
         where P: Into<Cow<'a, RequestPattern>>,
     {
-        self.send_json_request(Method::POST, "/requests/count", &request_pattern.into())
-            .and_then(|mut response| response.json::<VerificationResult>())
+        let request_pattern = request_pattern.into();
+        if request_pattern.has_inline_custom_matcher() {
+            return self.find_requests_matching(request_pattern).map(count_found_requests);
+        }
+        self.send_json_request(Method::POST, "/requests/count", &request_pattern)
+            .and_then(read_json::<VerificationResult>)
     }
 
     pub fn count<'a, P>(&self, request_pattern: P) -> Result<u32>
@@ -188,16 +285,19 @@ impl WireMock {
     {
         self.count_requests_matching(request_pattern)
             .and_then(|verification_result| {
-                verification_result.assert_request_journal_enabled();
-                Ok(verification_result.count().unwrap())
+                verification_result.check_request_journal_enabled()?;
+                verification_result.count().ok_or(Error::RequestJournalDisabled)
             })
     }
 
+    /// Finds the requests matching the pattern, applying an inline custom matcher client-side.
     pub fn find_requests_matching<'a, P>(&self, request_pattern: P) -> Result<FindRequestsResult>
         where P: Into<Cow<'a, RequestPattern>>,
     {
-        self.send_json_request(Method::POST, "/requests/find", &request_pattern.into())
-            .and_then(|mut response| response.json::<FindRequestsResult>())
+        let request_pattern = request_pattern.into();
+        self.send_json_request(Method::POST, "/requests/find", &request_pattern)
+            .and_then(read_json::<FindRequestsResult>)
+            .map(|find_requests_result| filter_inline_custom_matcher(&request_pattern, find_requests_result))
     }
 
     pub fn find<'a, P>(&self, request_pattern: P) -> Result<Vec<LoggedRequest>>
@@ -205,27 +305,27 @@ impl WireMock {
     {
         self.find_requests_matching(request_pattern)
             .and_then(|find_requests_result| {
-                find_requests_result.assert_request_journal_enabled();
+                find_requests_result.check_request_journal_enabled()?;
                 Ok(find_requests_result.into())
             })
     }
 
     pub fn find_unmatched_requests(&self) -> Result<FindRequestsResult> {
         self.send_empty_request(Method::GET, "/requests/unmatched")
-            .and_then(|mut response| response.json::<FindRequestsResult>())
+            .and_then(read_json::<FindRequestsResult>)
     }
 
     pub fn find_unmatched(&self) -> Result<Vec<LoggedRequest>> {
         self.find_unmatched_requests()
             .and_then(|find_requests_result| {
-                find_requests_result.assert_request_journal_enabled();
+                find_requests_result.check_request_journal_enabled()?;
                 Ok(find_requests_result.into())
             })
     }
 
     pub fn find_top_near_misses_for_request(&self, logged_request: &LoggedRequest) -> Result<FindNearMissesResult> {
         self.send_json_request(Method::POST, "/near-misses/request", logged_request)
-            .and_then(|mut response| response.json::<FindNearMissesResult>())
+            .and_then(read_json::<FindNearMissesResult>)
     }
 
     pub fn find_near_misses_for_request(&self, logged_request: &LoggedRequest) -> Result<Vec<NearMiss>> {
@@ -237,7 +337,7 @@ impl WireMock {
         where P: Into<Cow<'a, RequestPattern>>,
     {
         self.send_json_request(Method::POST, "/near-misses/request-pattern", &request_pattern.into())
-            .and_then(|mut response| response.json::<FindNearMissesResult>())
+            .and_then(read_json::<FindNearMissesResult>)
     }
 
     pub fn find_near_misses_for<'a, P>(&self, request_pattern: P) -> Result<Vec<NearMiss>>
@@ -249,7 +349,7 @@ impl WireMock {
 
     pub fn find_top_near_misses_for_unmatched_requests(&self) -> Result<FindNearMissesResult> {
         self.send_empty_request(Method::GET, "/requests/unmatched/near-misses")
-            .and_then(|mut response| response.json::<FindNearMissesResult>())
+            .and_then(read_json::<FindNearMissesResult>)
     }
 
     pub fn find_near_misses_for_unmatched_requests(&self) -> Result<Vec<NearMiss>> {
@@ -259,7 +359,7 @@ impl WireMock {
 
     pub fn get_all_scenarios(&self) -> Result<GetScenariosResult> {
         self.send_empty_request(Method::GET, "/scenarios")
-            .and_then(|mut response| response.json::<GetScenariosResult>())
+            .and_then(read_json::<GetScenariosResult>)
     }
 
     pub fn get_scenarios(&self) -> Result<Vec<Scenario>> {
@@ -277,7 +377,7 @@ impl WireMock {
     {
         let content_pattern: ContentPattern = pattern.into();
         self.send_json_request(Method::POST, "/mappings/find-by-metadata", &content_pattern)
-            .and_then(|mut response| response.json::<ListStubMappingsResult>())
+            .and_then(read_json::<ListStubMappingsResult>)
     }
 
     pub fn find_stubs_by_metadata<P>(&self, pattern: P) -> Result<Vec<StubMapping>>
@@ -303,6 +403,71 @@ impl WireMock {
             .map(|_| stub_import.into())
     }
 
+    /// Imports the stub mappings loaded by [`load_mappings_dir`](../stubbing/fn.load_mappings_dir.html).
+    pub fn import_stubs_from_dir<P>(&self, path: P, duplicate_policy: StubImportDuplicatePolicy) -> Result<Vec<StubMapping>>
+        where P: AsRef<Path>,
+    {
+        self.import_stubs(stub_import_from_dir(path.as_ref(), duplicate_policy)?)
+    }
+
+    /// Writes the current stub mappings with [`write_mappings_dir`](../stubbing/fn.write_mappings_dir.html).
+    pub fn export_mappings_to_dir<P>(&self, path: P, options: MappingsExportOptions) -> Result<Vec<PathBuf>>
+        where P: AsRef<Path>,
+    {
+        write_mappings_dir(path, self.list_stub_mappings()?, options)
+    }
+
+    /// Fails with `Error::UnsupportedInlineCustomMatcher` if the request filter has an inline custom matcher.
+    pub fn start_recording<S>(&self, record_spec: S) -> Result<()>
+        where S: Into<RecordSpec>,
+    {
+        let record_spec = record_spec.into();
+        if record_spec.has_inline_custom_matcher() {
+            return Err(Error::UnsupportedInlineCustomMatcher);
+        }
+        self.send_json_request(Method::POST, "/recordings/start", &record_spec)
+            .map(|_| ())
+    }
+
+    pub fn stop_recording_result(&self) -> Result<SnapshotRecordResult> {
+        self.send_empty_request(Method::POST, "/recordings/stop")
+            .and_then(read_json::<SnapshotRecordResult>)
+    }
+
+    pub fn stop_recording(&self) -> Result<Vec<StubMapping>> {
+        self.stop_recording_result()
+            .map(SnapshotRecordResult::into)
+    }
+
+    pub fn get_recording_status_result(&self) -> Result<RecordingStatusResult> {
+        self.send_empty_request(Method::GET, "/recordings/status")
+            .and_then(read_json::<RecordingStatusResult>)
+    }
+
+    pub fn get_recording_status(&self) -> Result<RecordingStatus> {
+        self.get_recording_status_result()
+            .map(RecordingStatusResult::into)
+    }
+
+    /// Fails with `Error::UnsupportedInlineCustomMatcher` if the request filter has an inline custom matcher.
+    pub fn snapshot_record_result<S>(&self, record_spec: S) -> Result<SnapshotRecordResult>
+        where S: Into<RecordSpec>,
+    {
+        let record_spec = record_spec.into();
+        if record_spec.has_inline_custom_matcher() {
+            return Err(Error::UnsupportedInlineCustomMatcher);
+        }
+        self.send_json_request(Method::POST, "/recordings/snapshot", &record_spec)
+            .and_then(read_json::<SnapshotRecordResult>)
+    }
+
+    pub fn snapshot_record<S>(&self, record_spec: S) -> Result<Vec<StubMapping>>
+        where S: Into<RecordSpec>,
+    {
+        self.snapshot_record_result(record_spec)
+            .map(SnapshotRecordResult::into)
+    }
+
     pub fn set_global_fixed_delay(&self, milliseconds: u16) -> Result<()> {
         let settings = self.get_global_settings()?
             .clone_to_builder()
@@ -326,7 +491,7 @@ impl WireMock {
 
     pub fn get_global_settings_result(&self) -> Result<GetGlobalSettingsResult> {
         self.send_empty_request(Method::GET, "/settings")
-            .and_then(|mut response| response.json::<GetGlobalSettingsResult>())
+            .and_then(read_json::<GetGlobalSettingsResult>)
     }
 
     pub fn get_global_settings(&self) -> Result<GlobalSettings> {
@@ -334,22 +499,77 @@ impl WireMock {
             .map(GetGlobalSettingsResult::into)
     }
 
+    /// Lists the names of the body files (relative to the `__files` directory).
+    pub fn list_files(&self) -> Result<Vec<String>> {
+        self.send_empty_request(Method::GET, "/files")
+            .and_then(read_json::<Vec<String>>)
+    }
+
+    pub fn get_file(&self, name: &str) -> Result<Option<Vec<u8>>> {
+        self.send_empty_request(Method::GET, &file_path(name))
+            .and_then(read_bytes)
+            .map(Some)
+            .or_else(map_not_found_error_to_none)
+    }
+
+    /// Creates or replaces the body file.
+    pub fn put_file<B>(&self, name: &str, content: B) -> Result<()>
+        where B: Into<Vec<u8>>,
+    {
+        self.send_bytes_request(Method::PUT, &file_path(name), content.into())
+            .map(|_| ())
+    }
+
+    /// Deletes the body file and returns whether it existed.
+    pub fn delete_file(&self, name: &str) -> Result<bool> {
+        self.send_empty_request(Method::DELETE, &file_path(name))
+            .map(|_| true)
+            .or_else(|error| map_not_found_error_to(error, false))
+    }
+
+    /// Uploads the body file and returns a response with it as body.
+    pub fn upload_body_file_and_stub<B>(&self, name: &str, content: B) -> Result<ResponseDefinitionBuilder>
+        where B: Into<Vec<u8>>,
+    {
+        self.put_file(name, content)?;
+        Ok(a_response().with_body_file(name))
+    }
 
     fn send_empty_request(&self, method: Method, path: &str) -> Result<Response> {
         let request = self.create_request(method, path);
 
-        request.send()
-            .and_then(|response| response.error_for_status())
+        let response = request.send()?;
+        check_status(response)
+    }
+
+    fn send_query_request<Q>(&self, method: Method, path: &str, query: &Q) -> Result<Response>
+        where Q: Serialize + ?Sized
+    {
+        let request = self.create_request(method, path);
+
+        let response = request.query(query).send()?;
+        check_status(response)
     }
 
     fn send_json_request<T>(&self, method: Method, path: &str, json: &T) -> Result<Response>
         where T: Serialize + ?Sized
     {
         let request = self.create_request(method, path);
+        let body = serde_json::to_vec(json)?;
 
-        request.json(json)
-            .send()
-            .and_then(|response| response.error_for_status())
+        let response = request.header(http::header::CONTENT_TYPE, "application/json")
+            .body(body)
+            .send()?;
+        check_status(response)
+    }
+
+    fn send_bytes_request(&self, method: Method, path: &str, bytes: Vec<u8>) -> Result<Response> {
+        let request = self.create_request(method, path);
+
+        let response = request.header(http::header::CONTENT_TYPE, "application/octet-stream")
+            .body(bytes)
+            .send()?;
+        check_status(response)
     }
 
     fn create_request(&self, method: Method, path: &str) -> RequestBuilder {
@@ -370,20 +590,110 @@ impl WireMock {
     }
 }
 
+#[derive(Serialize)]
+struct ScenarioState<'a> {
+    state: &'a str,
+}
+
+fn scenario_state_path(scenario_name: &str) -> String {
+    format!("/scenarios/{}/state", utf8_percent_encode(scenario_name, NON_ALPHANUMERIC))
+}
+
+/// Encodes the segments of a (possibly nested) file name.
+fn file_path(name: &str) -> String {
+    let segments = name.split('/')
+        .map(|segment| utf8_percent_encode(segment, NON_ALPHANUMERIC).to_string())
+        .collect::<Vec<String>>();
+    format!("/files/{}", segments.join("/"))
+}
+
+fn stub_import_from_dir(path: &Path, duplicate_policy: StubImportDuplicatePolicy) -> Result<StubImport> {
+    let builder = load_mappings_dir(path)?.into_iter()
+        .fold(stub_import(), |builder, stub_mapping| builder.stub(stub_mapping));
+    let builder = match duplicate_policy {
+        StubImportDuplicatePolicy::OVERWRITE => builder.overwrite_existing(),
+        StubImportDuplicatePolicy::IGNORE => builder.ignore_existing(),
+    };
+    Ok(builder.build())
+}
+
+fn map_scenario_error(error: Error, scenario_name: &str, state: Option<&str>) -> Error {
+    match (error.status(), state) {
+        (Some(StatusCode::NOT_FOUND), _) => Error::ScenarioNotFound {
+            scenario_name: scenario_name.to_string(),
+        },
+        (Some(StatusCode::BAD_REQUEST), Some(state)) => Error::InvalidScenarioState {
+            scenario_name: scenario_name.to_string(),
+            state: state.to_string(),
+        },
+        _ => error,
+    }
+}
+
+fn filter_inline_custom_matcher(request_pattern: &RequestPattern, mut find_requests_result: FindRequestsResult)
+    -> FindRequestsResult
+{
+    if let Some(inline_custom_matcher) = request_pattern.inline_custom_matcher() {
+        find_requests_result.requests.retain(|request| inline_custom_matcher.matches(request));
+    }
+    find_requests_result
+}
+
+/// WireMock cannot count with an inline custom matcher, so the filtered requests are counted instead.
+fn count_found_requests(find_requests_result: FindRequestsResult) -> VerificationResult {
+    VerificationResult {
+        count: Some(find_requests_result.requests.len() as u32),
+        request_journal_disabled: find_requests_result.request_journal_disabled(),
+    }
+}
+
 fn map_not_found_error_to_none<T>(error: Error) -> Result<Option<T>> {
     map_not_found_error_to(error, None)
 }
 
 fn map_not_found_error_to<T>(error: Error, value_on_not_found_error: T) -> Result<T> {
-    if let Some(status_code) = error.status() {
-        if status_code == StatusCode::NOT_FOUND {
-            return Ok(value_on_not_found_error);
-        }
+    if error.is_not_found() {
+        return Ok(value_on_not_found_error);
     }
 
     Err(error)
 }
 
+fn check_status(mut response: Response) -> Result<Response> {
+    if let Some(status) = error_status(response.status().as_u16()) {
+        let body = response.text().unwrap_or_default();
+        return Err(Error::Http { status, body });
+    }
+
+    Ok(response)
+}
+
+/// Returns the status code if it is a client or server error.
+///
+/// Takes the raw code because the async client is built on a newer version of the http crate.
+fn error_status(status_code: u16) -> Option<StatusCode> {
+    StatusCode::from_u16(status_code).ok()
+        .filter(|status| status.is_client_error() || status.is_server_error())
+}
+
+fn read_bytes(mut response: Response) -> Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+    response.copy_to(&mut bytes)?;
+    Ok(bytes)
+}
+
+fn read_json<T>(mut response: Response) -> Result<T>
+    where T: DeserializeOwned,
+{
+    parse_json(&response.text()?)
+}
+
+fn parse_json<T>(body: &str) -> Result<T>
+    where T: DeserializeOwned,
+{
+    Ok(serde_json::from_str(body)?)
+}
+
 #[cfg(test)]
 mod compile_only_dsl_examples {
     use crate::http::Fault;
@@ -443,14 +753,14 @@ mod compile_only_dsl_examples {
             .will_return(ok()));
     }
 
-//    #[test]
-//    #[ignore = "this is a test that only checks if the code compiles"]
-//    fn multipart_body_matching_base64() {
-//        stub_for(post(url_equal_to("/with/multipart"))
-//            .withMultipartRequestBody(aMultipart()
-//                .with_body(binary_equal_to("Content")))
-//            .will_return(ok()));
-//    }
+    #[test]
+    #[ignore = "this is a test that only checks if the code compiles"]
+    fn multipart_body_matching_base64() {
+        stub_for(post(url_equal_to("/with/multipart"))
+            .with_multipart_request_body(a_multipart()
+                .with_body(binary_equal_to("Content")))
+            .will_return(ok()));
+    }
 
     #[test]
     #[ignore = "this is a test that only checks if the code compiles"]
@@ -576,21 +886,21 @@ mod compile_only_dsl_examples {
             .will_return(a_response().with_status(200)));
     }
 
-//    #[test]
-//    #[ignore = "this is a test that only checks if the code compiles"]
-//    fn advanced_xpath_matching() {
-//        stub_for(put(url_equal_to("/xpath"))
-//            .with_request_body(matching_xpath("//todo-item/text()", containing("wash")))
-//            .will_return(a_response().with_status(200)));
-//    }
+    #[test]
+    #[ignore = "this is a test that only checks if the code compiles"]
+    fn advanced_xpath_matching() {
+        stub_for(put(url_equal_to("/xpath"))
+            .with_request_body(matching_xpath_with("//todo-item/text()", containing("wash")))
+            .will_return(a_response().with_status(200)));
+    }
 
-//    #[test]
-//    #[ignore = "this is a test that only checks if the code compiles"]
-//    fn advanced_jsonpath_matching() {
-//        stub_for(put(url_equal_to("/jsonpath"))
-//            .with_request_body(matching_json_path("$..todoItem", containing("wash")))
-//            .will_return(a_response().with_status(200)));
-//    }
+    #[test]
+    #[ignore = "this is a test that only checks if the code compiles"]
+    fn advanced_jsonpath_matching() {
+        stub_for(put(url_equal_to("/jsonpath"))
+            .with_request_body(matching_json_path_with("$..todoItem", containing("wash")))
+            .will_return(a_response().with_status(200)));
+    }
 
     #[test]
     #[ignore = "this is a test that only checks if the code compiles"]
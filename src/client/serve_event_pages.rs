@@ -0,0 +1,145 @@
+use std::collections::HashSet;
+
+use uuid::Uuid;
+
+use crate::client::WireMock;
+use crate::error::{Error, Result};
+use crate::model::{GetServeEventsResult, PageParams, PaginatedResult};
+use crate::stubbing::ServeEvent;
+
+/// Lazily walks the request journal page by page, newest serve events first.
+///
+/// Created with [`WireMock::serve_events_pages`](struct.WireMock.html#method.serve_events_pages).
+/// Every page is fetched on demand and the walk ends once [`Meta::total`](model/struct.Meta.html#method.total)
+/// serve events have been read or the journal has no older serve events.
+///
+/// WireMock's request journal only supports `limit` and `since`, so the `offset` is ignored.
+/// Instead, every page request raises the limit by the page limit and skips the serve events
+/// of the previous pages, which means that later pages transfer all previous serve events again.
+/// Serve events that are logged during the walk are returned with one of the next pages.
+///
+/// A page limit of 0 yields an [`Error::InvalidPageLimit`](enum.Error.html#variant.InvalidPageLimit)
+/// and a page without new serve events before the end of the journal yields an
+/// [`Error::StalledServeEventPages`](enum.Error.html#variant.StalledServeEventPages).
+pub struct ServeEventPages<'a> {
+    wire_mock: &'a WireMock,
+    walk: ServeEventWalk,
+}
+
+impl<'a> ServeEventPages<'a> {
+    pub(crate) fn new(wire_mock: &'a WireMock, page_params: PageParams) -> ServeEventPages<'a> {
+        ServeEventPages {
+            wire_mock,
+            walk: ServeEventWalk::new(page_params),
+        }
+    }
+}
+
+impl<'a> Iterator for ServeEventPages<'a> {
+    type Item = Result<Vec<ServeEvent>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let page_params = match self.walk.next_page_params()? {
+            Ok(page_params) => page_params,
+            Err(error) => return Some(Err(error)),
+        };
+        let result = self.wire_mock.get_serve_events_page(page_params);
+        self.walk.read_page(page_params, result)
+    }
+}
+
+/// The state of a walk through the request journal, shared by the blocking and the async client.
+pub(crate) struct ServeEventWalk {
+    page_params: PageParams,
+    read: u32,
+    seen_ids: HashSet<Uuid>,
+    done: bool,
+}
+
+impl ServeEventWalk {
+    pub(crate) fn new(page_params: PageParams) -> ServeEventWalk {
+        ServeEventWalk {
+            page_params,
+            read: 0,
+            seen_ids: HashSet::new(),
+            done: false,
+        }
+    }
+
+    /// The parameters to request the next page with, or `None` once the walk has ended.
+    pub(crate) fn next_page_params(&mut self) -> Option<Result<PageParams>> {
+        if self.done {
+            return None;
+        }
+
+        match self.page_params.limit() {
+            Some(0) => {
+                self.done = true;
+                Some(Err(Error::InvalidPageLimit))
+            },
+            Some(limit) => Some(Ok(self.page_params.with_limit(self.read.saturating_add(limit)))),
+            None => Some(Ok(self.page_params)),
+        }
+    }
+
+    /// Selects the serve events of the requested page that were not read before.
+    pub(crate) fn read_page(&mut self, page_params: PageParams, result: Result<GetServeEventsResult>)
+        -> Option<Result<Vec<ServeEvent>>>
+    {
+        let result = match result {
+            Ok(result) => result,
+            Err(error) => {
+                self.done = true;
+                return Some(Err(error));
+            },
+        };
+
+        let total = result.meta().total();
+        let is_journal_end = match page_params.limit() {
+            Some(limit) => (result.selection().len() as u32) < limit,
+            None => true,
+        };
+        let serve_events: Vec<ServeEvent> = result.into();
+        let serve_events = serve_events.into_iter()
+            .filter(|serve_event| self.seen_ids.insert(*serve_event.id()))
+            .collect::<Vec<ServeEvent>>();
+        self.read += serve_events.len() as u32;
+        self.done = is_journal_end || self.read >= total;
+
+        if !serve_events.is_empty() {
+            Some(Ok(serve_events))
+        } else if is_journal_end {
+            None
+        } else {
+            self.done = true;
+            Some(Err(Error::StalledServeEventPages { read: self.read, total }))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    #[test]
+    fn raises_the_limit_and_stalls_on_a_full_page_without_new_serve_events() {
+        let mut json: Value = serde_json::from_str(include_str!("../../tests/golden/serve_events.json")).unwrap();
+        json["meta"]["total"] = json!(3);
+        let page = || serde_json::from_value::<GetServeEventsResult>(json.clone()).map_err(Error::from);
+        let mut walk = ServeEventWalk::new(PageParams::default().with_limit(1));
+
+        let page_params = walk.next_page_params().unwrap().unwrap();
+        assert_eq!(page_params.limit(), Some(1));
+        assert_eq!(walk.read_page(page_params, page()).unwrap().unwrap().len(), 1);
+
+        let page_params = walk.next_page_params().unwrap().unwrap();
+        assert_eq!(page_params.limit(), Some(2));
+        match walk.read_page(page_params.with_limit(1), page()) {
+            Some(Err(Error::StalledServeEventPages { read: 1, total: 3 })) => {},
+            result => panic!("expected stalled serve event pages, got: {:?}", result),
+        }
+        assert!(walk.next_page_params().is_none());
+    }
+}
@@ -5,11 +5,11 @@ use std::iter::FromIterator;
 
 use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
-use serde::export::fmt::Display;
+use std::fmt::Display;
 
 use crate::extension::Parameters;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct Metadata {
     #[serde(flatten)]
     pub(crate) metadata: serde_json::Map<String, serde_json::Value>,
@@ -39,16 +39,16 @@ impl Metadata {
         self.metadata.is_empty()
     }
 
-    pub fn get<K: ?Sized>(&self, key: &K) -> Option<&serde_json::Value>
+    pub fn get<K>(&self, key: &K) -> Option<&serde_json::Value>
         where String: Borrow<K>,
-              K: Ord + Eq + Hash + Display,
+              K: ?Sized + Ord + Eq + Hash + Display,
     {
         self.metadata.get(key)
     }
 
-    pub fn get_str<K: ?Sized>(&self, key: &K) -> Option<&str>
+    pub fn get_str<K>(&self, key: &K) -> Option<&str>
         where String: Borrow<K>,
-              K: Ord + Eq + Hash + Display,
+              K: ?Sized + Ord + Eq + Hash + Display,
     {
         self.metadata.get(key)
             .map(|value| {
@@ -59,9 +59,9 @@ impl Metadata {
             })
     }
 
-    pub fn get_i64<K: ?Sized>(&self, key: &K) -> Option<i64>
+    pub fn get_i64<K>(&self, key: &K) -> Option<i64>
         where String: Borrow<K>,
-              K: Ord + Eq + Hash + Display,
+              K: ?Sized + Ord + Eq + Hash + Display,
     {
         self.metadata.get(key)
             .map(|value| {
@@ -72,9 +72,9 @@ impl Metadata {
             })
     }
 
-    pub fn get_u64<K: ?Sized>(&self, key: &K) -> Option<u64>
+    pub fn get_u64<K>(&self, key: &K) -> Option<u64>
         where String: Borrow<K>,
-              K: Ord + Eq + Hash + Display,
+              K: ?Sized + Ord + Eq + Hash + Display,
     {
         self.metadata.get(key)
             .map(|value| {
@@ -85,9 +85,9 @@ impl Metadata {
             })
     }
 
-    pub fn get_f64<K: ?Sized>(&self, key: &K) -> Option<f64>
+    pub fn get_f64<K>(&self, key: &K) -> Option<f64>
         where String: Borrow<K>,
-              K: Ord + Eq + Hash + Display,
+              K: ?Sized + Ord + Eq + Hash + Display,
     {
         self.metadata.get(key)
             .map(|value| {
@@ -98,9 +98,9 @@ impl Metadata {
             })
     }
 
-    pub fn get_bool<K: ?Sized>(&self, key: &K) -> Option<bool>
+    pub fn get_bool<K>(&self, key: &K) -> Option<bool>
         where String: Borrow<K>,
-              K: Ord + Eq + Hash + Display,
+              K: ?Sized + Ord + Eq + Hash + Display,
     {
         self.metadata.get(key)
             .map(|value| {
@@ -111,9 +111,9 @@ impl Metadata {
             })
     }
 
-    pub fn get_array<K: ?Sized>(&self, key: &K) -> Option<&Vec<serde_json::Value>>
+    pub fn get_array<K>(&self, key: &K) -> Option<&Vec<serde_json::Value>>
         where String: Borrow<K>,
-              K: Ord + Eq + Hash + Display,
+              K: ?Sized + Ord + Eq + Hash + Display,
     {
         self.metadata.get(key)
             .map(|value| {
@@ -124,9 +124,9 @@ impl Metadata {
             })
     }
 
-    pub fn get_mapped_array<K: ?Sized, F, V>(&self, key: &K, value_mapping: F) -> Option<Vec<V>>
+    pub fn get_mapped_array<K, F, V>(&self, key: &K, value_mapping: F) -> Option<Vec<V>>
         where String: Borrow<K>,
-              K: Ord + Eq + Hash + Display,
+              K: ?Sized + Ord + Eq + Hash + Display,
               F: Fn(&serde_json::Value) -> V,
     {
         self.metadata.get(key)
@@ -135,15 +135,15 @@ impl Metadata {
                     .unwrap_or_else(|| {
                         panic!("key {} is not of type array, got: {}", key, value)
                     })
-                    .into_iter()
-                    .map(|value| value_mapping(value))
+                    .iter()
+                    .map(value_mapping)
                     .collect::<Vec<V>>()
             })
     }
 
-    pub fn get_object<K: ?Sized>(&self, key: &K) -> Option<&serde_json::Map<String, serde_json::Value>>
+    pub fn get_object<K>(&self, key: &K) -> Option<&serde_json::Map<String, serde_json::Value>>
         where String: Borrow<K>,
-              K: Ord + Eq + Hash + Display,
+              K: ?Sized + Ord + Eq + Hash + Display,
     {
         self.metadata.get(key)
             .map(|value| {
@@ -154,9 +154,9 @@ impl Metadata {
             })
     }
 
-    pub fn get_metadata<K: ?Sized>(&self, key: &K) -> Option<Metadata>
+    pub fn get_metadata<K>(&self, key: &K) -> Option<Metadata>
         where String: Borrow<K>,
-              K: Ord + Eq + Hash + Display,
+              K: ?Sized + Ord + Eq + Hash + Display,
     {
         self.metadata.get(key)
             .map(|value| {
@@ -189,7 +189,7 @@ impl From<&serde_json::Map<String, serde_json::Value>> for Metadata {
 
 impl From<serde_json::Map<String, serde_json::Value>> for Metadata {
     fn from(metadata: serde_json::Map<String, serde_json::Value>) -> Metadata {
-        Metadata::from_iter(metadata.into_iter())
+        Metadata::from_iter(metadata)
     }
 }
 
@@ -207,13 +207,13 @@ impl From<serde_json::Value> for Metadata {
 
 impl From<HashMap<String, serde_json::Value>> for Metadata {
     fn from(metadata: HashMap<String, serde_json::Value>) -> Metadata {
-        Metadata::from_iter(metadata.into_iter())
+        Metadata::from_iter(metadata)
     }
 }
 
 impl From<IndexMap<String, serde_json::Value>> for Metadata {
     fn from(metadata: IndexMap<String, serde_json::Value>) -> Metadata {
-        Metadata::from_iter(metadata.into_iter())
+        Metadata::from_iter(metadata)
     }
 }
 
@@ -250,9 +250,9 @@ impl<'a> FromIterator<(&'a String, &'a serde_json::Value)> for Metadata {
     }
 }
 
-impl Into<serde_json::Value> for Metadata {
-    fn into(self) -> serde_json::Value {
-        serde_json::to_value(self.metadata).unwrap()
+impl From<Metadata> for serde_json::Value {
+    fn from(metadata: Metadata) -> serde_json::Value {
+        serde_json::to_value(metadata.metadata).unwrap()
     }
 }
 
@@ -297,7 +297,7 @@ impl MetadataBuilder {
 
 impl From<serde_json::Map<String, serde_json::Value>> for MetadataBuilder {
     fn from(metadata: serde_json::Map<String, serde_json::Value>) -> MetadataBuilder {
-        MetadataBuilder::from_iter(metadata.into_iter())
+        MetadataBuilder::from_iter(metadata)
     }
 }
 
@@ -315,7 +315,7 @@ impl From<serde_json::Value> for MetadataBuilder {
 
 impl From<HashMap<String, serde_json::Value>> for MetadataBuilder {
     fn from(metadata: HashMap<String, serde_json::Value>) -> MetadataBuilder {
-        MetadataBuilder::from_iter(metadata.into_iter())
+        MetadataBuilder::from_iter(metadata)
     }
 }
 
@@ -335,8 +335,8 @@ impl FromIterator<(String, serde_json::Value)> for MetadataBuilder {
     }
 }
 
-impl Into<serde_json::Value> for MetadataBuilder {
-    fn into(self) -> serde_json::Value {
-        serde_json::to_value(self.metadata).unwrap()
+impl From<MetadataBuilder> for serde_json::Value {
+    fn from(builder: MetadataBuilder) -> serde_json::Value {
+        serde_json::to_value(builder.metadata).unwrap()
     }
 }
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq, Eq, Hash)]
+#[serde(into = "TimingFields")]
 pub struct Timing {
     #[serde(rename = "addedDelay", with = "crate::serde::u32_negative_to_option")]
     added_delay: Option<u32>,
@@ -46,3 +47,30 @@ impl Timing {
         }
     }
 }
+
+/// Includes the calculated times like WireMock does.
+#[derive(Serialize)]
+struct TimingFields {
+    #[serde(rename = "addedDelay", with = "crate::serde::u32_negative_to_option")]
+    added_delay: Option<u32>,
+    #[serde(rename = "processTime", with = "crate::serde::u32_negative_to_option")]
+    process_time: Option<u32>,
+    #[serde(rename = "responseSendTime", with = "crate::serde::u32_negative_to_option")]
+    response_send_time: Option<u32>,
+    #[serde(rename = "serveTime", with = "crate::serde::u32_negative_to_option")]
+    serve_time: Option<u32>,
+    #[serde(rename = "totalTime", with = "crate::serde::u32_negative_to_option")]
+    total_time: Option<u32>,
+}
+
+impl From<Timing> for TimingFields {
+    fn from(timing: Timing) -> TimingFields {
+        TimingFields {
+            added_delay: timing.added_delay,
+            process_time: timing.process_time,
+            response_send_time: timing.response_send_time,
+            serve_time: timing.serve_time(),
+            total_time: timing.total_time(),
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use std::error::Error as StdError;
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+
+use http::StatusCode;
+
+use crate::verification::VerificationError;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Errors that can occur while talking to the WireMock admin API.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// The request could not be sent or the response could not be received,
+    /// e.g. because the connection was refused.
+    Transport(reqwest::Error),
+    /// Same as `Transport`, but raised by the async client.
+    #[cfg(feature = "async")]
+    AsyncTransport(reqwest_async::Error),
+    /// WireMock answered with a client or server error status.
+    Http {
+        status: StatusCode,
+        /// The response body that WireMock sent back, usually containing the error details.
+        body: String,
+    },
+    /// A request body could not be encoded or a response body could not be decoded.
+    Json(serde_json::Error),
+    /// The request journal is disabled,
+    /// so no verification or request searching operations are available.
+    RequestJournalDisabled,
+    /// The requests received by WireMock did not match the expected count.
+    Verification(Box<VerificationError>),
+    /// No scenario with the given name exists.
+    ScenarioNotFound {
+        scenario_name: String,
+    },
+    /// The scenario exists, but the state is not one of its possible states.
+    InvalidScenarioState {
+        scenario_name: String,
+        state: String,
+    },
+    /// The request journal cannot be walked page by page with a page limit of 0.
+    InvalidPageLimit,
+    /// A page of the request journal contained no new serve events
+    /// although not all serve events have been read.
+    StalledServeEventPages {
+        read: u32,
+        total: u32,
+    },
+    /// The operation is performed by WireMock alone,
+    /// so it cannot apply the inline custom matcher of the request pattern.
+    UnsupportedInlineCustomMatcher,
+    /// A mappings file or directory could not be read or written.
+    Io {
+        path: PathBuf,
+        error: io::Error,
+    },
+    /// A mappings file does not contain a valid stub mapping.
+    InvalidMappingFile {
+        path: PathBuf,
+        /// The path to the invalid JSON value within the file, e.g. `mappings[1].request.method`.
+        json_path: String,
+        error: serde_json::Error,
+    },
+}
+
+impl Error {
+    /// The HTTP status code if WireMock responded with an error status.
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            Error::Http { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+
+    pub fn is_not_found(&self) -> bool {
+        self.status() == Some(StatusCode::NOT_FOUND)
+    }
+
+    pub fn is_transport(&self) -> bool {
+        match self {
+            Error::Transport(_) => true,
+            #[cfg(feature = "async")]
+            Error::AsyncTransport(_) => true,
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Transport(error) => write!(f, "failed to communicate with WireMock: {}", error),
+            #[cfg(feature = "async")]
+            Error::AsyncTransport(error) => write!(f, "failed to communicate with WireMock: {}", error),
+            Error::Http { status, body } => {
+                if body.is_empty() {
+                    write!(f, "received error status {} from WireMock", status)
+                } else {
+                    write!(f, "received error status {} from WireMock: {}", status, body)
+                }
+            },
+            Error::Json(error) => write!(f, "invalid JSON: {}", error),
+            Error::RequestJournalDisabled => f.write_str("the request journal is disabled, \
+                so no verification or request searching operations are available"),
+            Error::Verification(error) => fmt::Display::fmt(error, f),
+            Error::ScenarioNotFound { scenario_name } => {
+                write!(f, "scenario {} does not exist", scenario_name)
+            },
+            Error::InvalidScenarioState { scenario_name, state } => {
+                write!(f, "scenario {} does not support state {}", scenario_name, state)
+            },
+            Error::InvalidPageLimit => f.write_str("the page limit must be greater than 0"),
+            Error::StalledServeEventPages { read, total } => {
+                write!(f, "the request journal returned no new serve events after {} of {}", read, total)
+            },
+            Error::UnsupportedInlineCustomMatcher => {
+                f.write_str("request patterns with an inline custom matcher are not supported by this operation")
+            },
+            Error::Io { path, error } => write!(f, "failed to access {}: {}", path.display(), error),
+            Error::InvalidMappingFile { path, json_path, error } => {
+                write!(f, "invalid stub mapping in {} at {}: {}", path.display(), json_path, error)
+            },
+        }
+    }
+}
+
+impl StdError for Error {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            Error::Transport(error) => Some(error),
+            #[cfg(feature = "async")]
+            Error::AsyncTransport(error) => Some(error),
+            Error::Json(error) => Some(error),
+            Error::Verification(error) => Some(error.as_ref()),
+            Error::Io { error, .. } => Some(error),
+            Error::InvalidMappingFile { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(error: reqwest::Error) -> Error {
+        Error::Transport(error)
+    }
+}
+
+#[cfg(feature = "async")]
+impl From<reqwest_async::Error> for Error {
+    fn from(error: reqwest_async::Error) -> Error {
+        Error::AsyncTransport(error)
+    }
+}
+
+impl From<VerificationError> for Error {
+    fn from(error: VerificationError) -> Error {
+        Error::Verification(Box::new(error))
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(error: serde_json::Error) -> Error {
+        Error::Json(error)
+    }
+}
@@ -0,0 +1,7 @@
+pub use crate::extension::parameters::Parameters;
+pub use crate::extension::response_templating::{RandomValueType, Template, RESPONSE_TEMPLATE};
+pub use crate::extension::webhook_definition::{WebhookDefinition, WEBHOOK};
+
+mod parameters;
+mod response_templating;
+mod webhook_definition;
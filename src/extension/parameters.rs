@@ -5,9 +5,9 @@ use std::iter::FromIterator;
 
 use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
-use serde::export::fmt::Display;
+use std::fmt::Display;
 
-#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct Parameters {
     #[serde(flatten)]
     pub(crate) parameters: serde_json::Map<String, serde_json::Value>,
@@ -49,16 +49,16 @@ impl Parameters {
         self.parameters.is_empty()
     }
 
-    pub fn get<K: ?Sized>(&self, key: &K) -> Option<&serde_json::Value>
+    pub fn get<K>(&self, key: &K) -> Option<&serde_json::Value>
         where String: Borrow<K>,
-              K: Ord + Eq + Hash + Display,
+              K: ?Sized + Ord + Eq + Hash + Display,
     {
         self.parameters.get(key)
     }
 
-    pub fn get_str<K: ?Sized>(&self, key: &K) -> Option<&str>
+    pub fn get_str<K>(&self, key: &K) -> Option<&str>
         where String: Borrow<K>,
-              K: Ord + Eq + Hash + Display,
+              K: ?Sized + Ord + Eq + Hash + Display,
     {
         self.parameters.get(key)
             .map(|value| {
@@ -69,9 +69,9 @@ impl Parameters {
             })
     }
 
-    pub fn get_i64<K: ?Sized>(&self, key: &K) -> Option<i64>
+    pub fn get_i64<K>(&self, key: &K) -> Option<i64>
         where String: Borrow<K>,
-              K: Ord + Eq + Hash + Display,
+              K: ?Sized + Ord + Eq + Hash + Display,
     {
         self.parameters.get(key)
             .map(|value| {
@@ -82,9 +82,9 @@ impl Parameters {
             })
     }
 
-    pub fn get_u64<K: ?Sized>(&self, key: &K) -> Option<u64>
+    pub fn get_u64<K>(&self, key: &K) -> Option<u64>
         where String: Borrow<K>,
-              K: Ord + Eq + Hash + Display,
+              K: ?Sized + Ord + Eq + Hash + Display,
     {
         self.parameters.get(key)
             .map(|value| {
@@ -95,9 +95,9 @@ impl Parameters {
             })
     }
 
-    pub fn get_f64<K: ?Sized>(&self, key: &K) -> Option<f64>
+    pub fn get_f64<K>(&self, key: &K) -> Option<f64>
         where String: Borrow<K>,
-              K: Ord + Eq + Hash + Display,
+              K: ?Sized + Ord + Eq + Hash + Display,
     {
         self.parameters.get(key)
             .map(|value| {
@@ -108,9 +108,9 @@ impl Parameters {
             })
     }
 
-    pub fn get_bool<K: ?Sized>(&self, key: &K) -> Option<bool>
+    pub fn get_bool<K>(&self, key: &K) -> Option<bool>
         where String: Borrow<K>,
-              K: Ord + Eq + Hash + Display,
+              K: ?Sized + Ord + Eq + Hash + Display,
     {
         self.parameters.get(key)
             .map(|value| {
@@ -121,9 +121,9 @@ impl Parameters {
             })
     }
 
-    pub fn get_array<K: ?Sized>(&self, key: &K) -> Option<&Vec<serde_json::Value>>
+    pub fn get_array<K>(&self, key: &K) -> Option<&Vec<serde_json::Value>>
         where String: Borrow<K>,
-              K: Ord + Eq + Hash + Display,
+              K: ?Sized + Ord + Eq + Hash + Display,
     {
         self.parameters.get(key)
             .map(|value| {
@@ -134,9 +134,9 @@ impl Parameters {
             })
     }
 
-    pub fn get_mapped_array<K: ?Sized, F, V>(&self, key: &K, value_mapping: F) -> Option<Vec<V>>
+    pub fn get_mapped_array<K, F, V>(&self, key: &K, value_mapping: F) -> Option<Vec<V>>
         where String: Borrow<K>,
-              K: Ord + Eq + Hash + Display,
+              K: ?Sized + Ord + Eq + Hash + Display,
               F: Fn(&serde_json::Value) -> V,
     {
         self.parameters.get(key)
@@ -145,15 +145,15 @@ impl Parameters {
                     .unwrap_or_else(|| {
                         panic!("key {} is not of type array, got: {}", key, value)
                     })
-                    .into_iter()
-                    .map(|value| value_mapping(value))
+                    .iter()
+                    .map(value_mapping)
                     .collect::<Vec<V>>()
             })
     }
 
-    pub fn get_object<K: ?Sized>(&self, key: &K) -> Option<&serde_json::Map<String, serde_json::Value>>
+    pub fn get_object<K>(&self, key: &K) -> Option<&serde_json::Map<String, serde_json::Value>>
         where String: Borrow<K>,
-              K: Ord + Eq + Hash + Display,
+              K: ?Sized + Ord + Eq + Hash + Display,
     {
         self.parameters.get(key)
             .map(|value| {
@@ -164,9 +164,9 @@ impl Parameters {
             })
     }
 
-    pub fn get_parameters<K: ?Sized>(&self, key: &K) -> Option<Parameters>
+    pub fn get_parameters<K>(&self, key: &K) -> Option<Parameters>
         where String: Borrow<K>,
-              K: Ord + Eq + Hash + Display,
+              K: ?Sized + Ord + Eq + Hash + Display,
     {
         self.parameters.get(key)
             .map(|value| {
@@ -187,7 +187,7 @@ impl From<&serde_json::Map<String, serde_json::Value>> for Parameters {
 
 impl From<serde_json::Map<String, serde_json::Value>> for Parameters {
     fn from(parameters: serde_json::Map<String, serde_json::Value>) -> Parameters {
-        Parameters::from_iter(parameters.into_iter())
+        Parameters::from_iter(parameters)
     }
 }
 
@@ -205,13 +205,13 @@ impl From<serde_json::Value> for Parameters {
 
 impl From<HashMap<String, serde_json::Value>> for Parameters {
     fn from(parameters: HashMap<String, serde_json::Value>) -> Parameters {
-        Parameters::from_iter(parameters.into_iter())
+        Parameters::from_iter(parameters)
     }
 }
 
 impl From<IndexMap<String, serde_json::Value>> for Parameters {
     fn from(parameters: IndexMap<String, serde_json::Value>) -> Parameters {
-        Parameters::from_iter(parameters.into_iter())
+        Parameters::from_iter(parameters)
     }
 }
 
@@ -240,8 +240,8 @@ impl<'a> FromIterator<(&'a String, &'a serde_json::Value)> for Parameters {
     }
 }
 
-impl Into<serde_json::Value> for Parameters {
-    fn into(self) -> serde_json::Value {
-        serde_json::to_value(self.parameters).unwrap()
+impl From<Parameters> for serde_json::Value {
+    fn from(parameters: Parameters) -> serde_json::Value {
+        serde_json::to_value(parameters.parameters).unwrap()
     }
 }
@@ -0,0 +1,290 @@
+use std::fmt;
+
+use crate::http::Body;
+use crate::matching::DateTimeUnit;
+
+/// The name of WireMock's response templating transformer.
+pub const RESPONSE_TEMPLATE: &str = "response-template";
+
+/// The request in the model of response templates.
+const REQUEST: &str = "request";
+/// The served request in the model of webhook templates.
+const ORIGINAL_REQUEST: &str = "originalRequest";
+
+/// A Handlebars expression for WireMock's response templating.
+///
+/// Names and arguments are quoted, so the expression stays valid whatever they contain.
+/// Templates can be used as (or formatted into) response bodies and header values:
+///
+/// ```rust
+/// use wiremock_client::*;
+/// use wiremock_client::extension::Template;
+///
+/// let response = ok()
+///     .with_templated_header("X-Request-Id", Template::request_header("X-Request-Id"))
+///     .with_templated_body(format!("Hello {}!", Template::request_query("name")));
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Template(String);
+
+impl Template {
+    /// The request URL including the query, e.g. `/things?id=1`.
+    pub fn request_url() -> Template {
+        Template::url(REQUEST)
+    }
+
+    /// The request URL without the query, e.g. `/things`.
+    pub fn request_path() -> Template {
+        Template::path(REQUEST)
+    }
+
+    /// The zero based segment of the request path, e.g. `things` at index `0` of `/things/1`.
+    pub fn request_path_segment(index: usize) -> Template {
+        Template::path_segment(REQUEST, index)
+    }
+
+    /// The (first) value of the query parameter.
+    pub fn request_query<S: AsRef<str>>(name: S) -> Template {
+        Template::lookup(REQUEST, "query", name.as_ref())
+    }
+
+    /// The (first) value of the request header.
+    pub fn request_header<S: AsRef<str>>(name: S) -> Template {
+        Template::lookup(REQUEST, "headers", name.as_ref())
+    }
+
+    /// The value of the request cookie.
+    pub fn request_cookie<S: AsRef<str>>(name: S) -> Template {
+        Template::lookup(REQUEST, "cookies", name.as_ref())
+    }
+
+    pub fn request_body() -> Template {
+        Template::body(REQUEST)
+    }
+
+    /// The result of the JSONPath expression applied to the request body.
+    pub fn json_path<S: AsRef<str>>(expression: S) -> Template {
+        Template::body_json_path(REQUEST, expression.as_ref())
+    }
+
+    /// Like `request_url`, but for the values of a webhook, which refer to the served request as `originalRequest`.
+    pub fn original_request_url() -> Template {
+        Template::url(ORIGINAL_REQUEST)
+    }
+
+    /// Like `request_path`, but for the values of a webhook.
+    pub fn original_request_path() -> Template {
+        Template::path(ORIGINAL_REQUEST)
+    }
+
+    /// Like `request_path_segment`, but for the values of a webhook.
+    pub fn original_request_path_segment(index: usize) -> Template {
+        Template::path_segment(ORIGINAL_REQUEST, index)
+    }
+
+    /// Like `request_query`, but for the values of a webhook.
+    pub fn original_request_query<S: AsRef<str>>(name: S) -> Template {
+        Template::lookup(ORIGINAL_REQUEST, "query", name.as_ref())
+    }
+
+    /// Like `request_header`, but for the values of a webhook.
+    pub fn original_request_header<S: AsRef<str>>(name: S) -> Template {
+        Template::lookup(ORIGINAL_REQUEST, "headers", name.as_ref())
+    }
+
+    /// Like `request_cookie`, but for the values of a webhook.
+    pub fn original_request_cookie<S: AsRef<str>>(name: S) -> Template {
+        Template::lookup(ORIGINAL_REQUEST, "cookies", name.as_ref())
+    }
+
+    /// Like `request_body`, but for the values of a webhook.
+    pub fn original_request_body() -> Template {
+        Template::body(ORIGINAL_REQUEST)
+    }
+
+    /// Like `json_path`, but applied to the body of the served request in the values of a webhook.
+    pub fn original_request_json_path<S: AsRef<str>>(expression: S) -> Template {
+        Template::body_json_path(ORIGINAL_REQUEST, expression.as_ref())
+    }
+
+    /// A random value of the type with the length.
+    pub fn random_value(value_type: RandomValueType, length: usize) -> Template {
+        Template::expression(&format!("randomValue length={} type='{}'", length, value_type))
+    }
+
+    pub fn random_uuid() -> Template {
+        Template::expression("randomValue type='UUID'")
+    }
+
+    /// The current date-time in ISO 8601 format.
+    pub fn now() -> Template {
+        Template::expression("now")
+    }
+
+    /// The current date-time in the Java `SimpleDateFormat` format, or `unix`/`epoch` for seconds/millis since epoch.
+    pub fn now_formatted<S: AsRef<str>>(format: S) -> Template {
+        Template::expression(&format!("now format={}", quote(format.as_ref())))
+    }
+
+    /// The current date-time moved by the amount of units, e.g. `-15 seconds`.
+    pub fn now_offset(amount: i64, unit: DateTimeUnit) -> Template {
+        Template::expression(&format!("now offset='{} {}'", amount, unit))
+    }
+
+    /// The current date-time moved by the amount of units in the format (see `now_formatted`).
+    pub fn now_offset_formatted<S: AsRef<str>>(amount: i64, unit: DateTimeUnit, format: S) -> Template {
+        Template::expression(&format!("now offset='{} {}' format={}", amount, unit, quote(format.as_ref())))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    fn expression(expression: &str) -> Template {
+        Template(format!("{{{{{}}}}}", expression))
+    }
+
+    fn url(request: &str) -> Template {
+        Template::expression(&format!("{}.url", request))
+    }
+
+    fn path(request: &str) -> Template {
+        Template::expression(&format!("{}.path", request))
+    }
+
+    fn path_segment(request: &str, index: usize) -> Template {
+        Template::expression(&format!("{}.pathSegments.[{}]", request, index))
+    }
+
+    fn body(request: &str) -> Template {
+        Template::expression(&format!("{}.body", request))
+    }
+
+    fn body_json_path(request: &str, expression: &str) -> Template {
+        Template::expression(&format!("jsonPath {}.body {}", request, quote(expression)))
+    }
+
+    /// Plain names are appended to the path, all others are looked up as string literal.
+    fn lookup(request: &str, field: &str, name: &str) -> Template {
+        let is_plain_name = !name.is_empty()
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+        if is_plain_name {
+            Template::expression(&format!("{}.{}.{}", request, field, name))
+        } else {
+            Template::expression(&format!("lookup {}.{} {}", request, field, quote(name)))
+        }
+    }
+}
+
+impl fmt::Display for Template {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl AsRef<str> for Template {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<Template> for String {
+    fn from(template: Template) -> String {
+        template.0
+    }
+}
+
+impl From<Template> for Body {
+    fn from(template: Template) -> Body {
+        Body::String(template.0)
+    }
+}
+
+/// The character set of a random value.
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Hash)]
+pub enum RandomValueType {
+    Alphabetic,
+    Alphanumeric,
+    AlphanumericAndSymbols,
+    Numeric,
+    Hexadecimal,
+}
+
+impl fmt::Display for RandomValueType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            RandomValueType::Alphabetic => "ALPHABETIC",
+            RandomValueType::Alphanumeric => "ALPHANUMERIC",
+            RandomValueType::AlphanumericAndSymbols => "ALPHANUMERIC_AND_SYMBOLS",
+            RandomValueType::Numeric => "NUMERIC",
+            RandomValueType::Hexadecimal => "HEXADECIMAL",
+        };
+        f.write_str(name)
+    }
+}
+
+/// A single quoted Handlebars string literal.
+fn quote(value: &str) -> String {
+    format!("'{}'", value.replace('\\', "\\\\").replace('\'', "\\'"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{any_url, created, get, ok};
+
+    use super::*;
+
+    #[test]
+    fn templating_keeps_the_status_fixed() {
+        let stub_mapping = get(any_url())
+            .will_return(created()
+                .with_templating()
+                .with_templated_header("Location", Template::request_path()))
+            .build();
+
+        let json = serde_json::to_value(&stub_mapping).unwrap();
+        assert_eq!(json["response"]["status"], 201);
+        assert_eq!(json["response"]["transformers"], serde_json::json!([RESPONSE_TEMPLATE]));
+        assert_eq!(json["response"]["headers"]["location"], "{{request.path}}");
+    }
+
+    #[test]
+    fn original_request_templates() {
+        assert_eq!(Template::original_request_url().as_str(), "{{originalRequest.url}}");
+        assert_eq!(Template::original_request_path_segment(2).as_str(), "{{originalRequest.pathSegments.[2]}}");
+        assert_eq!(Template::original_request_query("id").as_str(), "{{originalRequest.query.id}}");
+        assert_eq!(Template::original_request_header("X-Trace-Id").as_str(),
+            "{{lookup originalRequest.headers 'X-Trace-Id'}}");
+        assert_eq!(Template::original_request_json_path("$.id").as_str(), "{{jsonPath originalRequest.body '$.id'}}");
+    }
+
+    #[test]
+    fn serialize_response_templating() {
+        let stub_mapping = get(any_url())
+            .will_return(ok()
+                .with_transformer("body-transformer")
+                .with_templating()
+                .with_templated_header("X-Item", Template::request_path_segment(1))
+                .with_templated_header("X-Trace-Id", Template::request_header("X-Trace-Id"))
+                .with_templated_body(format!("{} {} {} {} {}",
+                    Template::request_query("search"),
+                    Template::json_path("$.names['first']"),
+                    Template::random_value(RandomValueType::Alphanumeric, 8),
+                    Template::now_offset_formatted(-3, DateTimeUnit::Days, "yyyy-MM-dd"),
+                    Template::random_uuid())))
+            .build();
+
+        let json = serde_json::to_value(&stub_mapping).unwrap();
+        assert_eq!(json["response"]["transformers"], serde_json::json!(["body-transformer", "response-template"]));
+        assert_eq!(json["response"]["headers"], serde_json::json!({
+            "x-item": "{{request.pathSegments.[1]}}",
+            "x-trace-id": "{{lookup request.headers 'X-Trace-Id'}}",
+        }));
+        assert_eq!(json["response"]["body"], concat!(
+            "{{request.query.search}} ",
+            r#"{{jsonPath request.body '$.names[\'first\']'}} "#,
+            "{{randomValue length=8 type='ALPHANUMERIC'}} ",
+            "{{now offset='-3 days' format='yyyy-MM-dd'}} ",
+            "{{randomValue type='UUID'}}"));
+    }
+}
@@ -0,0 +1,102 @@
+use http::HeaderMap;
+use serde::{Deserialize, Serialize};
+
+use crate::client::WebhookDefinitionBuilder;
+use crate::extension::Parameters;
+use crate::http::{DelayDistribution, RequestMethod};
+
+/// The name of WireMock's webhook post serve action.
+pub const WEBHOOK: &str = "webhook";
+
+/// An HTTP request WireMock sends after serving a stub.
+///
+/// The URL, headers and body are rendered by response templating,
+/// with the served request available as `originalRequest` (see the `Template::original_request_*` constructors).
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct WebhookDefinition {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) method: Option<RequestMethod>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) url: Option<String>,
+    #[serde(default, skip_serializing_if = "HeaderMap::is_empty", with = "crate::serde::header_map")]
+    pub(crate) headers: HeaderMap,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) body: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) delay: Option<DelayDistribution>,
+}
+
+impl WebhookDefinition {
+    pub fn method(&self) -> Option<&RequestMethod> {
+        self.method.as_ref()
+    }
+
+    pub fn url(&self) -> Option<&str> {
+        self.url.as_deref()
+    }
+
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    pub fn body(&self) -> Option<&str> {
+        self.body.as_deref()
+    }
+
+    pub fn delay(&self) -> Option<&DelayDistribution> {
+        self.delay.as_ref()
+    }
+}
+
+impl From<WebhookDefinitionBuilder> for WebhookDefinition {
+    fn from(builder: WebhookDefinitionBuilder) -> WebhookDefinition {
+        builder.build()
+    }
+}
+
+impl From<WebhookDefinition> for Parameters {
+    fn from(webhook: WebhookDefinition) -> Parameters {
+        Parameters::of(webhook)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ok, post, url_path_equal_to, webhook};
+    use crate::extension::Template;
+
+    use super::*;
+
+    #[test]
+    fn serialize_webhook() {
+        let stub_mapping = post(url_path_equal_to("/orders"))
+            .will_return(ok())
+            .with_webhook(webhook()
+                .with_method(RequestMethod::POST)
+                .with_url("http://localhost:8080/callback")
+                .with_header("Content-Type", "application/json")
+                .with_body(format!(r#"{{"order": "{}"}}"#, Template::original_request_json_path("$.id")))
+                .with_random_delay(DelayDistribution::Uniform { lower: 100, upper: 200 }))
+            .build();
+
+        let json = serde_json::to_value(&stub_mapping).unwrap();
+        assert_eq!(json["postServeActions"], serde_json::json!({
+            "webhook": {
+                "method": "POST",
+                "url": "http://localhost:8080/callback",
+                "headers": {"content-type": "application/json"},
+                "body": r#"{"order": "{{jsonPath originalRequest.body '$.id'}}"}"#,
+                "delay": {"type": "uniform", "lower": 100, "upper": 200},
+            },
+        }));
+
+        let webhook: WebhookDefinition = webhook()
+            .with_url("http://localhost:8080/callback")
+            .with_fixed_delay(500)
+            .into();
+        assert_eq!(serde_json::to_value(&webhook).unwrap(), serde_json::json!({
+            "url": "http://localhost:8080/callback",
+            "delay": {"type": "fixed", "milliseconds": 500},
+        }));
+    }
+}
@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 use crate::extension::Parameters;
 use crate::http::DelayDistribution;
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct GlobalSettings {
     #[serde(rename = "fixedDelay")]
     fixed_delay: Option<u16>,
@@ -42,6 +42,12 @@ pub struct GlobalSettingsBuilder {
     extended: Parameters,
 }
 
+impl Default for GlobalSettingsBuilder {
+    fn default() -> GlobalSettingsBuilder {
+        GlobalSettingsBuilder::new()
+    }
+}
+
 impl GlobalSettingsBuilder {
     pub fn new() -> GlobalSettingsBuilder {
         GlobalSettingsBuilder {
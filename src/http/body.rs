@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Hash)]
+#[non_exhaustive]
 pub enum Body {
     #[serde(rename = "body")]
     String(String),
@@ -9,12 +10,10 @@ pub enum Body {
     Base64(Vec<u8>),
     /// The response body as a JSON object.
     #[serde(rename = "jsonBody")]
-    Json(String),
+    Json(serde_json::Value),
     /// The path to the file containing the response body, relative to the configured file root.
     #[serde(rename = "bodyFileName")]
     FileName(String),
-    #[doc(hidden)]
-    __Nonexhaustive,
 }
 
 impl From<&str> for Body {
@@ -40,3 +39,69 @@ impl From<Vec<u8>> for Body {
         Body::Base64(body)
     }
 }
+
+impl From<serde_json::Value> for Body {
+    fn from(body: serde_json::Value) -> Body {
+        Body::Json(body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{any_url, equal_to_json, equal_to_json_value, ok, post, try_equal_to_json_value};
+    use crate::http::RequestMethod;
+    use crate::matching::evaluate;
+    use crate::stubbing::StubMapping;
+    use crate::verification::LoggedRequestBuilder;
+
+    use super::*;
+
+    #[test]
+    fn serialize_json_values() {
+        #[derive(serde::Serialize)]
+        struct Thing {
+            name: &'static str,
+            tags: Vec<&'static str>,
+        }
+
+        let stub_mapping = post(any_url())
+            .with_request_body(equal_to_json_value(Thing { name: "thing", tags: vec!["a", "b"] }))
+            .with_request_body(equal_to_json(r#"{"name": "thing"}"#).with_ignore_extra_elements(true))
+            .will_return(ok().with_json_body(serde_json::json!({"id": 1, "tags": ["a"]})))
+            .build();
+
+        let json = serde_json::to_value(&stub_mapping).unwrap();
+        assert_eq!(json["request"]["bodyPatterns"], serde_json::json!([
+            {"equalToJson": {"name": "thing", "tags": ["a", "b"]}},
+            {"equalToJson": r#"{"name": "thing"}"#, "ignoreExtraElements": true},
+        ]));
+        assert_eq!(json["response"]["jsonBody"], serde_json::json!({"id": 1, "tags": ["a"]}));
+
+        let stub_mapping: StubMapping = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(serde_json::to_value(&stub_mapping).unwrap(), json);
+        match stub_mapping.response().body() {
+            Some(Body::Json(body)) => assert_eq!(body["id"], 1),
+            body => panic!("expected a JSON body, got: {:?}", body),
+        }
+
+        let request = LoggedRequestBuilder::new(RequestMethod::POST, "/")
+            .with_body(r#"{"tags": ["a", "b"], "name": "thing"}"#)
+            .build();
+        assert!(evaluate(stub_mapping.request(), &request).is_exact_match());
+    }
+
+    #[test]
+    fn try_json_values_report_serialization_errors() {
+        struct Unserializable;
+
+        impl serde::Serialize for Unserializable {
+            fn serialize<S: serde::Serializer>(&self, _serializer: S) -> Result<S::Ok, S::Error> {
+                Err(serde::ser::Error::custom("not serializable"))
+            }
+        }
+
+        assert!(try_equal_to_json_value(Unserializable).is_err());
+        assert!(ok().try_with_json_body(Unserializable).is_err());
+        assert!(ok().try_with_json_body(serde_json::json!({"id": 1})).is_ok());
+    }
+}
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
 pub struct ChunkedDribbleDelay {
     #[serde(rename = "numberOfChunks")]
     pub(crate) number_of_chunks: u16,
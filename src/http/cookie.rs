@@ -1,10 +1,12 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::http::multi_value::MultiValue;
 
-#[derive(Debug, Serialize, Deserialize)]
+/// Serialized like WireMock as its value, or as list of values if there are multiple.
+/// The key is not serialized, but is the key of the cookie map.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Cookie {
-    key: String,
+    pub(crate) key: String,
     values: Vec<String>,
 }
 
@@ -23,6 +25,37 @@ impl Cookie {
     }
 }
 
+impl Serialize for Cookie {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        if self.is_single_valued() {
+            self.values[0].serialize(serializer)
+        } else {
+            self.values.serialize(serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Cookie {
+    fn deserialize<D>(deserializer: D) -> Result<Cookie, D::Error>
+        where D: Deserializer<'de>
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum CookieValues {
+            Single(String),
+            Multiple(Vec<String>),
+        }
+
+        let values = match CookieValues::deserialize(deserializer)? {
+            CookieValues::Single(value) => vec![value],
+            CookieValues::Multiple(values) => values,
+        };
+        Ok(Cookie::new(String::new(), values))
+    }
+}
+
 impl MultiValue for Cookie {
     fn is_present(&self) -> bool {
         !self.values.is_empty()
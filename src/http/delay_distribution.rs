@@ -1,7 +1,8 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(tag = "type")]
+#[non_exhaustive]
 pub enum DelayDistribution {
     #[serde(rename = "lognormal")]
     LogNormal {
@@ -13,6 +14,8 @@ pub enum DelayDistribution {
         lower: u32,
         upper: u32,
     },
-    #[doc(hidden)]
-    __Nonexhaustive,
+    #[serde(rename = "fixed")]
+    Fixed {
+        milliseconds: u32,
+    },
 }
@@ -1,13 +1,12 @@
 use serde::{Deserialize, Serialize};
 
 #[allow(non_camel_case_types)]
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone, Hash)]
 /// The fault to apply (instead of a full, valid response).
+#[non_exhaustive]
 pub enum Fault {
     CONNECTION_RESET_BY_PEER,
     EMPTY_RESPONSE,
     MALFORMED_RESPONSE_CHUNK,
     RANDOM_DATA_THEN_CLOSE,
-    #[doc(hidden)]
-    __Nonexhaustive,
 }
@@ -10,12 +10,12 @@ use encoding_rs::{Encoding, UTF_8};
 
 use crate::http::Fault;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct LoggedResponse {
     status: u16,
     #[serde(default, skip_serializing_if = "HeaderMap::is_empty", with = "crate::serde::header_map")]
     headers: HeaderMap,
-    #[serde(rename = "bodyAsBase64", default, with = "crate::serde::base64")]
+    #[serde(flatten, with = "crate::serde::logged_body")]
     body: Vec<u8>,
     #[serde(skip_serializing_if = "Option::is_none")]
     fault: Option<Fault>,
@@ -50,7 +50,7 @@ impl LoggedResponse {
     }
 
     #[cfg(feature = "decode")]
-    pub fn decode_body(&self) -> Option<Cow<str>> {
+    pub fn decode_body(&self) -> Option<Cow<'_, str>> {
         self.encoding_from_content_type_header()
             .unwrap_or(UTF_8)
             .decode_without_bom_handling_and_without_replacement(self.body())
@@ -1,4 +1,4 @@
-pub use http::{header, StatusCode};
+pub use http::{header, Method, StatusCode};
 
 pub use crate::http::body::Body;
 pub use crate::http::chunked_dribble_delay::ChunkedDribbleDelay;
@@ -6,8 +6,9 @@ pub use crate::http::cookie::Cookie;
 pub use crate::http::delay_distribution::DelayDistribution;
 pub use crate::http::fault::Fault;
 pub use crate::http::logged_response::LoggedResponse;
+pub use crate::http::multi_value::MultiValue;
 pub use crate::http::query_parameter::QueryParameter;
-pub use crate::http::request_method::RequestMethod;
+pub use crate::http::request_method::{InvalidHttpMethod, RequestMethod};
 pub use crate::http::response_definition::ResponseDefinition;
 
 mod request_method;
@@ -20,6 +21,3 @@ mod cookie;
 mod query_parameter;
 mod multi_value;
 mod logged_response;
-
-pub type Result<T> = reqwest::Result<T>;
-pub type Error = reqwest::Error;
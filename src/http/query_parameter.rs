@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::http::multi_value::MultiValue;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
 pub struct QueryParameter {
     key: String,
     values: Vec<String>,
@@ -1,8 +1,16 @@
-use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+use std::error::Error as StdError;
+use std::fmt;
 
+use http::Method;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Serialized as its name, e.g. `GET` or `PROPFIND`.
 #[allow(non_camel_case_types)]
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum RequestMethod {
+    /// Matches any method in request patterns.
     ANY,
     GET,
     POST,
@@ -13,6 +21,160 @@ pub enum RequestMethod {
     CONNECT,
     PATCH,
     TRACE,
-    #[doc(hidden)]
-    __Nonexhaustive,
+    /// An extension method like `PROPFIND`.
+    ///
+    /// Converting a name with `RequestMethod::from` only creates it for names that are not one of the other variants.
+    Other(String),
+}
+
+impl RequestMethod {
+    pub fn as_str(&self) -> &str {
+        match self {
+            RequestMethod::ANY => "ANY",
+            RequestMethod::GET => "GET",
+            RequestMethod::POST => "POST",
+            RequestMethod::PUT => "PUT",
+            RequestMethod::DELETE => "DELETE",
+            RequestMethod::HEAD => "HEAD",
+            RequestMethod::OPTIONS => "OPTIONS",
+            RequestMethod::CONNECT => "CONNECT",
+            RequestMethod::PATCH => "PATCH",
+            RequestMethod::TRACE => "TRACE",
+            RequestMethod::Other(method) => method,
+        }
+    }
+}
+
+impl fmt::Display for RequestMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Method names are case-sensitive, e.g. `get` is an extension method and not `GET`.
+impl From<&str> for RequestMethod {
+    fn from(method: &str) -> RequestMethod {
+        match method {
+            "ANY" => RequestMethod::ANY,
+            "GET" => RequestMethod::GET,
+            "POST" => RequestMethod::POST,
+            "PUT" => RequestMethod::PUT,
+            "DELETE" => RequestMethod::DELETE,
+            "HEAD" => RequestMethod::HEAD,
+            "OPTIONS" => RequestMethod::OPTIONS,
+            "CONNECT" => RequestMethod::CONNECT,
+            "PATCH" => RequestMethod::PATCH,
+            "TRACE" => RequestMethod::TRACE,
+            method => RequestMethod::Other(method.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RequestMethod {
+    fn from(method: String) -> RequestMethod {
+        RequestMethod::from(method.as_str())
+    }
+}
+
+impl From<&Method> for RequestMethod {
+    fn from(method: &Method) -> RequestMethod {
+        RequestMethod::from(method.as_str())
+    }
+}
+
+impl From<Method> for RequestMethod {
+    fn from(method: Method) -> RequestMethod {
+        RequestMethod::from(&method)
+    }
+}
+
+impl TryFrom<&RequestMethod> for Method {
+    type Error = InvalidHttpMethod;
+
+    fn try_from(method: &RequestMethod) -> Result<Method, InvalidHttpMethod> {
+        match method {
+            RequestMethod::ANY => Err(InvalidHttpMethod(method.clone())),
+            method => Method::from_bytes(method.as_str().as_bytes())
+                .map_err(|_invalid_method| InvalidHttpMethod(method.clone())),
+        }
+    }
+}
+
+impl TryFrom<RequestMethod> for Method {
+    type Error = InvalidHttpMethod;
+
+    fn try_from(method: RequestMethod) -> Result<Method, InvalidHttpMethod> {
+        Method::try_from(&method)
+    }
+}
+
+impl Serialize for RequestMethod {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for RequestMethod {
+    fn deserialize<D>(deserializer: D) -> Result<RequestMethod, D::Error>
+        where D: Deserializer<'de>
+    {
+        String::deserialize(deserializer).map(RequestMethod::from)
+    }
+}
+
+/// The request method is `ANY` or not a valid HTTP method, so it cannot be converted to an `http::Method`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidHttpMethod(RequestMethod);
+
+impl InvalidHttpMethod {
+    pub fn method(&self) -> &RequestMethod {
+        &self.0
+    }
+}
+
+impl fmt::Display for InvalidHttpMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} is not a valid HTTP method", self.0)
+    }
+}
+
+impl StdError for InvalidHttpMethod {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{request, requested_for, url_equal_to};
+    use crate::matching::evaluate;
+    use crate::stubbing::StubMapping;
+    use crate::verification::LoggedRequestBuilder;
+
+    use super::*;
+
+    #[test]
+    fn convert_request_methods() {
+        let propfind = Method::from_bytes(b"PROPFIND").unwrap();
+        assert_eq!(RequestMethod::from(Method::GET), RequestMethod::GET);
+        assert_eq!(RequestMethod::from("GET"), RequestMethod::GET);
+        assert_eq!(RequestMethod::from("get"), RequestMethod::Other("get".to_owned()));
+        assert_eq!(RequestMethod::from("propfind").to_string(), "propfind");
+        assert_ne!(RequestMethod::from("propfind"), RequestMethod::from(&propfind));
+        assert_eq!(Method::try_from(RequestMethod::from("propfind")), Ok(Method::from_bytes(b"propfind").unwrap()));
+        assert_eq!(RequestMethod::from(&propfind), RequestMethod::Other("PROPFIND".to_owned()));
+        assert_eq!(Method::try_from(RequestMethod::PATCH), Ok(Method::PATCH));
+        assert_eq!(Method::try_from(RequestMethod::from("PROPFIND")), Ok(propfind.clone()));
+        assert!(Method::try_from(RequestMethod::ANY).is_err());
+        assert!(Method::try_from(RequestMethod::from("NOT A METHOD")).is_err());
+        assert_eq!(RequestMethod::Other("PROPFIND".to_owned()).to_string(), "PROPFIND");
+
+        let stub_mapping = request(propfind.clone(), url_equal_to("/files")).build();
+        assert_eq!(serde_json::to_value(&stub_mapping).unwrap()["request"]["method"], "PROPFIND");
+        let json = serde_json::json!({"request": {"method": "PROPFIND"}, "response": {}});
+        let deserialized: StubMapping = serde_json::from_value(json).unwrap();
+        assert_eq!(deserialized.request().method(), &RequestMethod::Other("PROPFIND".to_owned()));
+
+        let logged_request = LoggedRequestBuilder::new(propfind.clone().into(), "/files").build();
+        assert!(evaluate(&requested_for(propfind, url_equal_to("/files")).build(), &logged_request).is_exact_match());
+        assert!(!evaluate(&requested_for(Method::GET, url_equal_to("/files")).build(), &logged_request).is_exact_match());
+    }
 }
@@ -5,9 +5,10 @@ use crate::client::{ProxyResponseDefinitionBuilder, ResponseDefinitionBuilder};
 use crate::extension::Parameters;
 use crate::http::{Body, ChunkedDribbleDelay, DelayDistribution, Fault};
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct ResponseDefinition {
     /// The HTTP status code to be returned.
+    #[serde(default = "crate::serde::default_status")]
     pub(crate) status: u16,
     /// The HTTP status message to be returned.
     #[serde(rename = "statusMessage", skip_serializing_if = "Option::is_none")]
@@ -38,11 +39,11 @@ pub struct ResponseDefinition {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub(crate) transformers: Vec<String>,
     /// Parameters to apply to response transformers.
-    #[serde(rename = "transformerParameters", flatten, default, skip_serializing_if = "Parameters::is_empty")]
+    #[serde(rename = "transformerParameters", default, skip_serializing_if = "Parameters::is_empty")]
     pub(crate) transformer_parameters: Parameters,
     /// Read-only flag indicating false if this was the default, unmatched response. Not present otherwise.
-    #[serde(rename = "fromConfiguredStub", default = "crate::serde::default_true", skip_serializing)]
-    pub(crate) from_configured_stub: bool,
+    #[serde(rename = "fromConfiguredStub", skip_serializing_if = "Option::is_none")]
+    pub(crate) from_configured_stub: Option<bool>,
 }
 
 impl ResponseDefinition {
@@ -51,7 +52,7 @@ impl ResponseDefinition {
     }
 
     pub fn status_message(&self) -> Option<&str> {
-        self.status_message.as_ref().map(|status_message| status_message.as_str())
+        self.status_message.as_deref()
     }
 
     pub fn body(&self) -> Option<&Body> {
@@ -79,7 +80,7 @@ impl ResponseDefinition {
     }
 
     pub fn proxy_base_url(&self) -> Option<&str> {
-        self.proxy_base_url.as_ref().map(|proxy_base_url| proxy_base_url.as_str())
+        self.proxy_base_url.as_deref()
     }
 
     pub fn fault(&self) -> Option<Fault> {
@@ -93,6 +94,11 @@ impl ResponseDefinition {
     pub fn transformer_parameters(&self) -> &Parameters {
         &self.transformer_parameters
     }
+
+    /// False if this is the default response for an unmatched request.
+    pub fn is_from_configured_stub(&self) -> bool {
+        self.from_configured_stub.unwrap_or(true)
+    }
 }
 
 impl From<ResponseDefinitionBuilder> for ResponseDefinition {
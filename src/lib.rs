@@ -56,6 +56,7 @@ Some notable differences are:
 * Some additional DSL functions are added to the root path:
     * `stub_import`
     * `metadata`
+    * `record_spec`
 * `HttpHeaders` and `HttpHeader` are replaced with `HeaderMap` and `HeaderValue` of the `http` crate.
 * Some Java interfaces have been replaced with nonexhaustive enums instead of traits.
   This is hopefully is not a problem, because I expect those types only to be used via the provided DSL functions.
@@ -64,7 +65,9 @@ Some notable differences are:
 */
 
 pub use client::*;
+pub use crate::error::Error;
 
+pub mod error;
 pub mod global;
 pub mod security;
 pub mod matching;
@@ -75,4 +78,7 @@ mod client;
 pub mod common;
 pub mod extension;
 pub mod verification;
+pub mod recording;
+#[cfg(feature = "test-server")]
+pub mod test_server;
 mod serde;
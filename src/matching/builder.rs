@@ -1,8 +1,11 @@
 use std::borrow::Cow;
 use indexmap::IndexMap;
 
-use crate::matching::{UrlPattern, ContentPattern, RequestPattern, StringValuePattern, AbsentPattern};
+use crate::matching::{UrlPattern, ContentPattern, RequestPattern, StringValuePattern, AbsentPattern, ContainsPattern};
+use crate::matching::{CustomMatcherDefinition, InlineCustomMatcher, MultipartMatchingType, MultipartValuePattern};
+use crate::extension::Parameters;
 use crate::http::RequestMethod;
+use crate::verification::LoggedRequest;
 use crate::BasicCredentials;
 
 pub struct RequestPatternBuilder {
@@ -13,9 +16,9 @@ pub struct RequestPatternBuilder {
     body_patterns: Vec<ContentPattern>,
     cookies: IndexMap<String, ContentPattern>,
     basic_credentials: Option<BasicCredentials>,
-//    multiparts: Vec<MultipartValuePattern>,
-//    custom_matcher: ValueMatcher<Request>,
-//    custom_matcher_definition: CustomMatcherDefinition,
+    multiparts: Vec<MultipartValuePattern>,
+    custom_matcher: Option<CustomMatcherDefinition>,
+    inline_custom_matcher: Option<InlineCustomMatcher>,
 }
 
 impl RequestPatternBuilder {
@@ -28,6 +31,9 @@ impl RequestPatternBuilder {
             body_patterns: Vec::new(),
             cookies: IndexMap::new(),
             basic_credentials: None,
+            multiparts: Vec::new(),
+            custom_matcher: None,
+            inline_custom_matcher: None,
         }
     }
 
@@ -40,9 +46,20 @@ impl RequestPatternBuilder {
             body_patterns: Vec::new(),
             cookies: IndexMap::new(),
             basic_credentials: None,
+            multiparts: Vec::new(),
+            custom_matcher: None,
+            inline_custom_matcher: None,
         }
     }
 
+    pub(crate) fn for_custom_matcher<S, P>(custom_request_matcher_name: S, parameters: P) -> RequestPatternBuilder
+        where S: Into<String>,
+              P: Into<Parameters>,
+    {
+        RequestPatternBuilder::all_requests()
+            .and_matching(custom_request_matcher_name, parameters)
+    }
+
     pub fn with_url<S>(mut self, url: S) -> RequestPatternBuilder
         where S: Into<String>
     {
@@ -93,34 +110,45 @@ impl RequestPatternBuilder {
         self
     }
 
-//    public RequestPatternBuilder withRequestBodyPart(MultipartValuePattern multiPattern) {
-//        if (multiPattern != null) {
-//            multiparts.add(multiPattern);
-//        }
-//        return this;
-//    }
-//
-//    public RequestPatternBuilder withAnyRequestBodyPart(MultipartValuePatternBuilder multiPatternBuilder) {
-//        return withRequestBodyPart(multiPatternBuilder.matchingType(MultipartValuePattern.MatchingType.ANY).build());
-//    }
-//
-//    public RequestPatternBuilder withAllRequestBodyParts(MultipartValuePatternBuilder multiPatternBuilder) {
-//        return withRequestBodyPart(multiPatternBuilder.matchingType(MultipartValuePattern.MatchingType.ALL).build());
-//    }
-//
-//    public RequestPatternBuilder andMatching(ValueMatcher<Request> customMatcher) {
-//        this.customMatcher = customMatcher;
-//        return this;
-//    }
-//
-//    public RequestPatternBuilder andMatching(String customRequestMatcherName) {
-//        return andMatching(customRequestMatcherName, Parameters.empty());
-//    }
-//
-//    public RequestPatternBuilder andMatching(String customRequestMatcherName, Parameters parameters) {
-//        this.customMatcherDefinition = new CustomMatcherDefinition(customRequestMatcherName, parameters);
-//        return this;
-//    }
+    pub fn with_request_body_part(mut self, multipart_pattern: MultipartValuePattern) -> RequestPatternBuilder {
+        self.multiparts.push(multipart_pattern);
+        self
+    }
+
+    pub fn with_any_request_body_part(self, multipart_pattern_builder: MultipartValuePatternBuilder)
+                                      -> RequestPatternBuilder {
+        self.with_request_body_part(multipart_pattern_builder
+            .matching_type(MultipartMatchingType::Any)
+            .build())
+    }
+
+    pub fn with_all_request_body_parts(self, multipart_pattern_builder: MultipartValuePatternBuilder)
+                                       -> RequestPatternBuilder {
+        self.with_request_body_part(multipart_pattern_builder
+            .matching_type(MultipartMatchingType::All)
+            .build())
+    }
+
+    /// Additionally matches with the closure.
+    ///
+    /// The closure is evaluated by the client: counting, finding and verifying fetch the requests
+    /// matching the rest of the pattern from WireMock and filter them.
+    /// Removing serve events with such a pattern fails, because WireMock removes them on its own.
+    pub fn and_matching_fn<F>(mut self, custom_matcher: F) -> RequestPatternBuilder
+        where F: Fn(&LoggedRequest) -> bool + Send + Sync + 'static,
+    {
+        self.inline_custom_matcher = Some(InlineCustomMatcher::new(custom_matcher));
+        self
+    }
+
+    /// Additionally matches with the request matcher extension of the name registered in WireMock.
+    pub fn and_matching<S, P>(mut self, custom_request_matcher_name: S, parameters: P) -> RequestPatternBuilder
+        where S: Into<String>,
+              P: Into<Parameters>,
+    {
+        self.custom_matcher = Some(CustomMatcherDefinition::new(custom_request_matcher_name, parameters));
+        self
+    }
 
     pub fn build(self) -> RequestPattern {
         RequestPattern {
@@ -131,6 +159,9 @@ impl RequestPatternBuilder {
             cookies: self.cookies,
             basic_auth_credentials: self.basic_credentials,
             body_patterns: self.body_patterns,
+            multipart_patterns: self.multiparts,
+            custom_matcher: self.custom_matcher,
+            inline_custom_matcher: self.inline_custom_matcher,
         }
     }
 }
@@ -140,3 +171,73 @@ impl<'a> From<RequestPatternBuilder> for Cow<'a, RequestPattern> {
         Cow::Owned(builder.build())
     }
 }
+
+pub struct MultipartValuePatternBuilder {
+    name: Option<String>,
+    headers: IndexMap<String, ContentPattern>,
+    body_patterns: Vec<ContentPattern>,
+    matching_type: MultipartMatchingType,
+}
+
+impl MultipartValuePatternBuilder {
+    pub fn new() -> MultipartValuePatternBuilder {
+        MultipartValuePatternBuilder {
+            name: None,
+            headers: IndexMap::new(),
+            body_patterns: Vec::new(),
+            matching_type: MultipartMatchingType::Any,
+        }
+    }
+
+    /// Matches parts whose `Content-Disposition` header contains the name.
+    pub fn with_name<S>(mut self, name: S) -> MultipartValuePatternBuilder
+        where S: Into<String>,
+    {
+        let name = name.into();
+        self.headers.insert(String::from("Content-Disposition"),
+            ContainsPattern::new(format!("name=\"{}\"", name)).into());
+        self.name = Some(name);
+        self
+    }
+
+    pub fn with_header<S, P>(mut self, key: S, header_pattern: P) -> MultipartValuePatternBuilder
+        where S: Into<String>,
+              P: StringValuePattern,
+    {
+        self.headers.insert(key.into(), header_pattern.into());
+        self
+    }
+
+    pub fn with_body<P>(mut self, body_pattern: P) -> MultipartValuePatternBuilder
+        where P: Into<ContentPattern>,
+    {
+        self.body_patterns.push(body_pattern.into());
+        self
+    }
+
+    pub fn matching_type(mut self, matching_type: MultipartMatchingType) -> MultipartValuePatternBuilder {
+        self.matching_type = matching_type;
+        self
+    }
+
+    pub fn build(self) -> MultipartValuePattern {
+        MultipartValuePattern {
+            name: self.name,
+            matching_type: self.matching_type,
+            headers: self.headers,
+            body_patterns: self.body_patterns,
+        }
+    }
+}
+
+impl Default for MultipartValuePatternBuilder {
+    fn default() -> MultipartValuePatternBuilder {
+        MultipartValuePatternBuilder::new()
+    }
+}
+
+impl From<MultipartValuePatternBuilder> for MultipartValuePattern {
+    fn from(builder: MultipartValuePatternBuilder) -> MultipartValuePattern {
+        builder.build()
+    }
+}
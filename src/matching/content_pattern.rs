@@ -1,10 +1,15 @@
+use std::borrow::Cow;
 use std::fmt::Debug;
+use std::hash::{Hash, Hasher};
 
 use serde::{Deserialize, Serialize};
 use indexmap::IndexMap;
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+use crate::matching::{AfterDateTimePattern, BeforeDateTimePattern, EqualToDateTimePattern};
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
 #[serde(untagged)]
+#[non_exhaustive]
 pub enum ContentPattern {
     Absent(AbsentPattern),
     Anything(AnythingPattern),
@@ -17,19 +22,90 @@ pub enum ContentPattern {
     MatchesXPath(MatchesXPathPattern),
     Regex(RegexPattern),
     NegativeRegex(NegativeRegexPattern),
-    #[doc(hidden)]
-    __Nonexhaustive,
+    Before(BeforeDateTimePattern),
+    After(AfterDateTimePattern),
+    EqualToDateTime(EqualToDateTimePattern),
+    And(LogicalAndPattern),
+    Or(LogicalOrPattern),
+}
+
+impl ContentPattern {
+    /// The name of the matching operator as used in WireMock's JSON, e.g. `equalTo`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ContentPattern::Absent(_) => "absent",
+            ContentPattern::Anything(_) => "anything",
+            ContentPattern::BinaryEqualTo(_) => "binaryEqualTo",
+            ContentPattern::Contains(_) => "contains",
+            ContentPattern::EqualToJson(_) => "equalToJson",
+            ContentPattern::EqualTo(_) => "equalTo",
+            ContentPattern::EqualToXml(_) => "equalToXml",
+            ContentPattern::MatchesJsonPath(_) => "matchesJsonPath",
+            ContentPattern::MatchesXPath(_) => "matchesXPath",
+            ContentPattern::Regex(_) => "matches",
+            ContentPattern::NegativeRegex(_) => "doesNotMatch",
+            ContentPattern::Before(_) => "before",
+            ContentPattern::After(_) => "after",
+            ContentPattern::EqualToDateTime(_) => "equalToDateTime",
+            ContentPattern::And(_) => "and",
+            ContentPattern::Or(_) => "or",
+        }
+    }
+
+    /// The expected value of this pattern (binary values are base64 encoded).
+    pub fn expected(&self) -> Cow<'_, str> {
+        match self {
+            ContentPattern::Absent(pattern) => Cow::Borrowed(pattern.value()),
+            ContentPattern::Anything(pattern) => Cow::Borrowed(pattern.value()),
+            ContentPattern::BinaryEqualTo(pattern) => Cow::Owned(base64::encode(pattern.bytes())),
+            ContentPattern::Contains(pattern) => Cow::Borrowed(pattern.value()),
+            ContentPattern::EqualToJson(pattern) => Cow::Borrowed(pattern.value()),
+            ContentPattern::EqualTo(pattern) => Cow::Borrowed(pattern.value()),
+            ContentPattern::EqualToXml(pattern) => Cow::Borrowed(pattern.value()),
+            ContentPattern::MatchesJsonPath(pattern) => Cow::Borrowed(pattern.value()),
+            ContentPattern::MatchesXPath(pattern) => Cow::Borrowed(pattern.value()),
+            ContentPattern::Regex(pattern) => Cow::Borrowed(pattern.value()),
+            ContentPattern::NegativeRegex(pattern) => Cow::Borrowed(pattern.value()),
+            ContentPattern::Before(pattern) => Cow::Borrowed(pattern.value()),
+            ContentPattern::After(pattern) => Cow::Borrowed(pattern.value()),
+            ContentPattern::EqualToDateTime(pattern) => Cow::Borrowed(pattern.value()),
+            ContentPattern::And(pattern) => Cow::Borrowed(pattern.value()),
+            ContentPattern::Or(pattern) => Cow::Borrowed(pattern.value()),
+        }
+    }
 }
 
 pub trait StringValuePattern: Debug + Serialize + Deserialize<'static> + Into<ContentPattern> {
     fn value(&self) -> &str;
+
+    /// Matches only if both this and the other pattern match.
+    fn and<P>(self, other: P) -> LogicalAndPattern
+        where Self: Sized,
+              P: StringValuePattern,
+    {
+        LogicalAndPattern::new(vec![self.into(), other.into()])
+    }
+
+    /// Matches if this or the other pattern matches.
+    fn or<P>(self, other: P) -> LogicalOrPattern
+        where Self: Sized,
+              P: StringValuePattern,
+    {
+        LogicalOrPattern::new(vec![self.into(), other.into()])
+    }
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
 pub struct AbsentPattern {
     absent: serde_json::Value,
 }
 
+impl Default for AbsentPattern {
+    fn default() -> AbsentPattern {
+        AbsentPattern::new()
+    }
+}
+
 impl AbsentPattern {
     pub fn new() -> AbsentPattern {
         AbsentPattern {
@@ -44,17 +120,23 @@ impl StringValuePattern for AbsentPattern {
     }
 }
 
-impl Into<ContentPattern> for AbsentPattern {
-    fn into(self) -> ContentPattern {
-        ContentPattern::Absent(self)
+impl From<AbsentPattern> for ContentPattern {
+    fn from(pattern: AbsentPattern) -> ContentPattern {
+        ContentPattern::Absent(pattern)
     }
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
 pub struct AnythingPattern {
     anything: String,
 }
 
+impl Default for AnythingPattern {
+    fn default() -> AnythingPattern {
+        AnythingPattern::new()
+    }
+}
+
 impl AnythingPattern {
     pub fn new() -> AnythingPattern {
         AnythingPattern {
@@ -69,13 +151,13 @@ impl StringValuePattern for AnythingPattern {
     }
 }
 
-impl Into<ContentPattern> for AnythingPattern {
-    fn into(self) -> ContentPattern {
-        ContentPattern::Anything(self)
+impl From<AnythingPattern> for ContentPattern {
+    fn from(pattern: AnythingPattern) -> ContentPattern {
+        ContentPattern::Anything(pattern)
     }
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
 pub struct BinaryEqualToPattern {
     /// Base64 encoded string.
     #[serde(rename = "binaryEqualTo", with = "crate::serde::base64")]
@@ -88,15 +170,19 @@ impl BinaryEqualToPattern {
             binary_equal_to: bytes.into(),
         }
     }
+
+    pub fn bytes(&self) -> &[u8] {
+        &self.binary_equal_to
+    }
 }
 
-impl Into<ContentPattern> for BinaryEqualToPattern {
-    fn into(self) -> ContentPattern {
-        ContentPattern::BinaryEqualTo(self)
+impl From<BinaryEqualToPattern> for ContentPattern {
+    fn from(pattern: BinaryEqualToPattern) -> ContentPattern {
+        ContentPattern::BinaryEqualTo(pattern)
     }
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
 pub struct ContainsPattern {
     contains: String,
 }
@@ -115,29 +201,29 @@ impl StringValuePattern for ContainsPattern {
     }
 }
 
-impl Into<ContentPattern> for ContainsPattern {
-    fn into(self) -> ContentPattern {
-        ContentPattern::Contains(self)
+impl From<ContainsPattern> for ContentPattern {
+    fn from(pattern: ContainsPattern) -> ContentPattern {
+        ContentPattern::Contains(pattern)
     }
 }
 
+/// Matches JSON given either as a string or as a JSON value, which WireMock both accepts.
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(from = "EqualToJsonPatternFields")]
 pub struct EqualToJsonPattern {
     #[serde(rename = "equalToJson")]
-    equal_to_json: String,
+    equal_to_json: serde_json::Value,
     #[serde(rename = "ignoreArrayOrder", skip_serializing_if = "Option::is_none")]
     ignore_array_order: Option<bool>,
     #[serde(rename = "ignoreExtraElements", skip_serializing_if = "Option::is_none")]
     ignore_extra_elements: Option<bool>,
+    #[serde(skip)]
+    value: String,
 }
 
 impl EqualToJsonPattern {
     pub fn new<S: Into<String>>(json: S) -> EqualToJsonPattern {
-        EqualToJsonPattern {
-            equal_to_json: json.into(),
-            ignore_array_order: None,
-            ignore_extra_elements: None,
-        }
+        EqualToJsonPattern::from(serde_json::Value::String(json.into()))
     }
 
     pub fn with_ignore_array_order(mut self, ignore_array_order: bool) -> EqualToJsonPattern {
@@ -149,21 +235,93 @@ impl EqualToJsonPattern {
         self.ignore_extra_elements = Some(ignore_extra_elements);
         self
     }
+
+    pub fn is_ignore_array_order(&self) -> bool {
+        self.ignore_array_order.unwrap_or(false)
+    }
+
+    pub fn is_ignore_extra_elements(&self) -> bool {
+        self.ignore_extra_elements.unwrap_or(false)
+    }
+
+    /// The expected JSON as value, parsed if it was given as (valid JSON) string.
+    fn json(&self) -> Cow<'_, serde_json::Value> {
+        match &self.equal_to_json {
+            serde_json::Value::String(json) => serde_json::from_str(json)
+                .map(Cow::Owned)
+                .unwrap_or(Cow::Borrowed(&self.equal_to_json)),
+            json => Cow::Borrowed(json),
+        }
+    }
+}
+
+/// Compares the expected JSON structurally, no matter if it was given as string or as JSON value.
+impl PartialEq for EqualToJsonPattern {
+    fn eq(&self, other: &EqualToJsonPattern) -> bool {
+        self.json() == other.json()
+            && self.is_ignore_array_order() == other.is_ignore_array_order()
+            && self.is_ignore_extra_elements() == other.is_ignore_extra_elements()
+    }
+}
+
+impl Eq for EqualToJsonPattern {}
+
+impl Hash for EqualToJsonPattern {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.json().hash(state);
+        self.is_ignore_array_order().hash(state);
+        self.is_ignore_extra_elements().hash(state);
+    }
+}
+
+impl From<serde_json::Value> for EqualToJsonPattern {
+    fn from(json: serde_json::Value) -> EqualToJsonPattern {
+        EqualToJsonPattern::from(EqualToJsonPatternFields {
+            equal_to_json: json,
+            ignore_array_order: None,
+            ignore_extra_elements: None,
+        })
+    }
+}
+
+#[derive(Deserialize)]
+struct EqualToJsonPatternFields {
+    #[serde(rename = "equalToJson")]
+    equal_to_json: serde_json::Value,
+    #[serde(rename = "ignoreArrayOrder")]
+    ignore_array_order: Option<bool>,
+    #[serde(rename = "ignoreExtraElements")]
+    ignore_extra_elements: Option<bool>,
+}
+
+impl From<EqualToJsonPatternFields> for EqualToJsonPattern {
+    fn from(fields: EqualToJsonPatternFields) -> EqualToJsonPattern {
+        let value = match &fields.equal_to_json {
+            serde_json::Value::String(json) => json.clone(),
+            json => json.to_string(),
+        };
+        EqualToJsonPattern {
+            equal_to_json: fields.equal_to_json,
+            ignore_array_order: fields.ignore_array_order,
+            ignore_extra_elements: fields.ignore_extra_elements,
+            value,
+        }
+    }
 }
 
 impl StringValuePattern for EqualToJsonPattern {
     fn value(&self) -> &str {
-        &self.equal_to_json
+        &self.value
     }
 }
 
-impl Into<ContentPattern> for EqualToJsonPattern {
-    fn into(self) -> ContentPattern {
-        ContentPattern::EqualToJson(self)
+impl From<EqualToJsonPattern> for ContentPattern {
+    fn from(pattern: EqualToJsonPattern) -> ContentPattern {
+        ContentPattern::EqualToJson(pattern)
     }
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
 pub struct EqualToPattern {
     #[serde(rename = "equalTo")]
     equal_to: String,
@@ -183,6 +341,10 @@ impl EqualToPattern {
         self.case_insensitive = Some(case_insensitive);
         self
     }
+
+    pub fn is_case_insensitive(&self) -> bool {
+        self.case_insensitive.unwrap_or(false)
+    }
 }
 
 impl StringValuePattern for EqualToPattern {
@@ -191,13 +353,13 @@ impl StringValuePattern for EqualToPattern {
     }
 }
 
-impl Into<ContentPattern> for EqualToPattern {
-    fn into(self) -> ContentPattern {
-        ContentPattern::EqualTo(self)
+impl From<EqualToPattern> for ContentPattern {
+    fn from(pattern: EqualToPattern) -> ContentPattern {
+        ContentPattern::EqualTo(pattern)
     }
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
 pub struct EqualToXmlPattern {
     #[serde(rename = "equalToXml")]
     equal_to_xml: String,
@@ -231,6 +393,18 @@ impl EqualToXmlPattern {
         self.placeholder_closing_delimiter_regex = Some(closing_regex.into());
         self
     }
+
+    pub fn is_enable_placeholders(&self) -> bool {
+        self.enable_placeholders.unwrap_or(false)
+    }
+
+    pub fn placeholder_opening_delimiter_regex(&self) -> Option<&str> {
+        self.placeholder_opening_delimiter_regex.as_deref()
+    }
+
+    pub fn placeholder_closing_delimiter_regex(&self) -> Option<&str> {
+        self.placeholder_closing_delimiter_regex.as_deref()
+    }
 }
 
 impl StringValuePattern for EqualToXmlPattern {
@@ -239,75 +413,152 @@ impl StringValuePattern for EqualToXmlPattern {
     }
 }
 
-impl Into<ContentPattern> for EqualToXmlPattern {
-    fn into(self) -> ContentPattern {
-        ContentPattern::EqualToXml(self)
+impl From<EqualToXmlPattern> for ContentPattern {
+    fn from(pattern: EqualToXmlPattern) -> ContentPattern {
+        ContentPattern::EqualToXml(pattern)
     }
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
 pub struct MatchesJsonPathPattern {
     #[serde(rename = "matchesJsonPath")]
-    matches_json_path: String,
+    matches_json_path: PathExpression,
 }
 
 impl MatchesJsonPathPattern {
     pub fn new<S: Into<String>>(json_path: S) -> MatchesJsonPathPattern {
         MatchesJsonPathPattern {
-            matches_json_path: json_path.into(),
+            matches_json_path: PathExpression::Expression(json_path.into()),
         }
     }
+
+    /// Matches the result of the JSONPath expression against the pattern instead of checking for its presence.
+    pub fn with_value_pattern<P>(mut self, value_pattern: P) -> MatchesJsonPathPattern
+        where P: StringValuePattern,
+    {
+        self.matches_json_path = self.matches_json_path.with_value_pattern(value_pattern.into());
+        self
+    }
+
+    pub fn value_pattern(&self) -> Option<&ContentPattern> {
+        self.matches_json_path.value_pattern()
+    }
 }
 
 impl StringValuePattern for MatchesJsonPathPattern {
     fn value(&self) -> &str {
-        &self.matches_json_path
+        self.matches_json_path.expression()
     }
 }
 
-impl Into<ContentPattern> for MatchesJsonPathPattern {
-    fn into(self) -> ContentPattern {
-        ContentPattern::MatchesJsonPath(self)
+impl From<MatchesJsonPathPattern> for ContentPattern {
+    fn from(pattern: MatchesJsonPathPattern) -> ContentPattern {
+        ContentPattern::MatchesJsonPath(pattern)
     }
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct MatchesXPathPattern {
     #[serde(rename = "matchesXPath")]
-    matches_xpath: String,
-    #[serde(rename = "xPathNamespaces", default)]
+    matches_xpath: PathExpression,
+    #[serde(rename = "xPathNamespaces", default, skip_serializing_if = "IndexMap::is_empty")]
     namespaces: IndexMap<String, String>,
 }
 
 impl MatchesXPathPattern {
     pub fn new<S: Into<String>>(xpath: S) -> MatchesXPathPattern {
         MatchesXPathPattern {
-            matches_xpath: xpath.into(),
+            matches_xpath: PathExpression::Expression(xpath.into()),
             namespaces: IndexMap::new(),
         }
     }
 
+    /// Matches the result of the XPath expression against the pattern instead of checking for its presence.
+    pub fn with_value_pattern<P>(mut self, value_pattern: P) -> MatchesXPathPattern
+        where P: StringValuePattern,
+    {
+        self.matches_xpath = self.matches_xpath.with_value_pattern(value_pattern.into());
+        self
+    }
+
+    pub fn value_pattern(&self) -> Option<&ContentPattern> {
+        self.matches_xpath.value_pattern()
+    }
+
     pub fn with_xpath_namespace<S>(mut self, name: S, namespace_uri: S) -> MatchesXPathPattern
         where S: Into<String>
     {
         self.namespaces.insert(name.into(), namespace_uri.into());
         self
     }
+
+    pub fn xpath_namespaces(&self) -> &IndexMap<String, String> {
+        &self.namespaces
+    }
+}
+
+impl Hash for MatchesXPathPattern {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.matches_xpath.hash(state);
+        // equality of the namespaces ignores their order
+        let mut namespaces = self.namespaces.iter().collect::<Vec<(&String, &String)>>();
+        namespaces.sort();
+        namespaces.hash(state);
+    }
 }
 
 impl StringValuePattern for MatchesXPathPattern {
     fn value(&self) -> &str {
-        &self.matches_xpath
+        self.matches_xpath.expression()
     }
 }
 
-impl Into<ContentPattern> for MatchesXPathPattern {
-    fn into(self) -> ContentPattern {
-        ContentPattern::MatchesXPath(self)
+impl From<MatchesXPathPattern> for ContentPattern {
+    fn from(pattern: MatchesXPathPattern) -> ContentPattern {
+        ContentPattern::MatchesXPath(pattern)
     }
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// The expression of a JSONPath or XPath pattern, optionally with a pattern for its result.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[serde(untagged)]
+enum PathExpression {
+    Expression(String),
+    WithValuePattern {
+        expression: String,
+        #[serde(flatten)]
+        value_pattern: Box<ContentPattern>,
+    },
+}
+
+impl PathExpression {
+    fn expression(&self) -> &str {
+        match self {
+            PathExpression::Expression(expression) => expression,
+            PathExpression::WithValuePattern { expression, .. } => expression,
+        }
+    }
+
+    fn value_pattern(&self) -> Option<&ContentPattern> {
+        match self {
+            PathExpression::Expression(_) => None,
+            PathExpression::WithValuePattern { value_pattern, .. } => Some(value_pattern),
+        }
+    }
+
+    fn with_value_pattern(self, value_pattern: ContentPattern) -> PathExpression {
+        let expression = match self {
+            PathExpression::Expression(expression) => expression,
+            PathExpression::WithValuePattern { expression, .. } => expression,
+        };
+        PathExpression::WithValuePattern {
+            expression,
+            value_pattern: Box::new(value_pattern),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
 pub struct RegexPattern {
     matches: String,
 }
@@ -326,13 +577,13 @@ impl StringValuePattern for RegexPattern {
     }
 }
 
-impl Into<ContentPattern> for RegexPattern {
-    fn into(self) -> ContentPattern {
-        ContentPattern::Regex(self)
+impl From<RegexPattern> for ContentPattern {
+    fn from(pattern: RegexPattern) -> ContentPattern {
+        ContentPattern::Regex(pattern)
     }
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
 pub struct NegativeRegexPattern {
     #[serde(rename = "doesNotMatch")]
     does_not_match: String,
@@ -352,8 +603,172 @@ impl StringValuePattern for NegativeRegexPattern {
     }
 }
 
-impl Into<ContentPattern> for NegativeRegexPattern {
-    fn into(self) -> ContentPattern {
-        ContentPattern::NegativeRegex(self)
+impl From<NegativeRegexPattern> for ContentPattern {
+    fn from(pattern: NegativeRegexPattern) -> ContentPattern {
+        ContentPattern::NegativeRegex(pattern)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(from = "LogicalAndPatternOperands")]
+pub struct LogicalAndPattern {
+    and: Vec<ContentPattern>,
+    #[serde(skip)]
+    value: String,
+}
+
+impl LogicalAndPattern {
+    pub fn new<I>(operands: I) -> LogicalAndPattern
+        where I: IntoIterator,
+              I::Item: Into<ContentPattern>,
+    {
+        let operands = operands.into_iter()
+            .map(Into::into)
+            .collect::<Vec<ContentPattern>>();
+        LogicalAndPattern {
+            value: join_operands(&operands, " AND "),
+            and: operands,
+        }
+    }
+
+    pub fn operands(&self) -> &[ContentPattern] {
+        &self.and
+    }
+}
+
+#[derive(Deserialize)]
+struct LogicalAndPatternOperands {
+    and: Vec<ContentPattern>,
+}
+
+impl From<LogicalAndPatternOperands> for LogicalAndPattern {
+    fn from(operands: LogicalAndPatternOperands) -> LogicalAndPattern {
+        LogicalAndPattern::new(operands.and)
+    }
+}
+
+/// Compares the operands only, as the value is derived from them.
+impl PartialEq for LogicalAndPattern {
+    fn eq(&self, other: &LogicalAndPattern) -> bool {
+        self.and == other.and
+    }
+}
+
+impl Eq for LogicalAndPattern {}
+
+impl Hash for LogicalAndPattern {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.and.hash(state);
+    }
+}
+
+impl StringValuePattern for LogicalAndPattern {
+    fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl From<LogicalAndPattern> for ContentPattern {
+    fn from(pattern: LogicalAndPattern) -> ContentPattern {
+        ContentPattern::And(pattern)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(from = "LogicalOrPatternOperands")]
+pub struct LogicalOrPattern {
+    or: Vec<ContentPattern>,
+    #[serde(skip)]
+    value: String,
+}
+
+impl LogicalOrPattern {
+    pub fn new<I>(operands: I) -> LogicalOrPattern
+        where I: IntoIterator,
+              I::Item: Into<ContentPattern>,
+    {
+        let operands = operands.into_iter()
+            .map(Into::into)
+            .collect::<Vec<ContentPattern>>();
+        LogicalOrPattern {
+            value: join_operands(&operands, " OR "),
+            or: operands,
+        }
+    }
+
+    pub fn operands(&self) -> &[ContentPattern] {
+        &self.or
+    }
+}
+
+#[derive(Deserialize)]
+struct LogicalOrPatternOperands {
+    or: Vec<ContentPattern>,
+}
+
+impl From<LogicalOrPatternOperands> for LogicalOrPattern {
+    fn from(operands: LogicalOrPatternOperands) -> LogicalOrPattern {
+        LogicalOrPattern::new(operands.or)
+    }
+}
+
+/// Compares the operands only, as the value is derived from them.
+impl PartialEq for LogicalOrPattern {
+    fn eq(&self, other: &LogicalOrPattern) -> bool {
+        self.or == other.or
+    }
+}
+
+impl Eq for LogicalOrPattern {}
+
+impl Hash for LogicalOrPattern {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.or.hash(state);
+    }
+}
+
+impl StringValuePattern for LogicalOrPattern {
+    fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl From<LogicalOrPattern> for ContentPattern {
+    fn from(pattern: LogicalOrPattern) -> ContentPattern {
+        ContentPattern::Or(pattern)
+    }
+}
+
+/// Like WireMock's expected value of logical patterns, e.g. `contains json AND doesNotMatch .*legacy.*`.
+fn join_operands(operands: &[ContentPattern], separator: &str) -> String {
+    operands.iter()
+        .map(|operand| format!("{} {}", operand.name(), operand.expected()))
+        .collect::<Vec<String>>()
+        .join(separator)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::{containing, equal_to_json, equal_to_json_value, matching};
+
+    use super::*;
+
+    #[test]
+    fn compare_content_patterns() {
+        let json_string: ContentPattern = equal_to_json(r#"{"name": "Tom", "roles": ["admin"]}"#).into();
+        let json_value: ContentPattern = equal_to_json_value(serde_json::json!({"roles": ["admin"], "name": "Tom"})).into();
+        assert_eq!(json_string, json_value);
+        assert_ne!(json_string, equal_to_json(r#"{"name": "Tom"}"#).into());
+        assert_ne!(json_string, equal_to_json(r#"{"name": "Tom", "roles": ["admin"]}"#).with_ignore_array_order(true).into());
+        assert_eq!(containing("Tom").and(equal_to_json(r#"{"name":"Tom"}"#)),
+            containing("Tom").and(equal_to_json_value(serde_json::json!({"name": "Tom"}))));
+
+        let mut patterns = HashSet::new();
+        patterns.insert(json_string);
+        patterns.insert(json_value);
+        patterns.insert(matching("[a-z]+").into());
+        assert_eq!(patterns.len(), 2);
     }
 }
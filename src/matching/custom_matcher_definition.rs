@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+use crate::extension::Parameters;
+
+/// Reference to a request matcher extension registered in WireMock.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct CustomMatcherDefinition {
+    name: String,
+    #[serde(default, skip_serializing_if = "Parameters::is_empty")]
+    parameters: Parameters,
+}
+
+impl CustomMatcherDefinition {
+    pub fn new<S, P>(name: S, parameters: P) -> CustomMatcherDefinition
+        where S: Into<String>,
+              P: Into<Parameters>,
+    {
+        CustomMatcherDefinition {
+            name: name.into(),
+            parameters: parameters.into(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn parameters(&self) -> &Parameters {
+        &self.parameters
+    }
+}
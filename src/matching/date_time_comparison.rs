@@ -0,0 +1,254 @@
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+
+use chrono::{DateTime, Datelike, Duration, FixedOffset, Months, NaiveDate, NaiveDateTime, TimeZone, Timelike, Utc};
+
+use crate::matching::{DateTimePatternOptions, DateTimeTruncation, DateTimeUnit};
+
+const NOW: &str = "now";
+
+/// Compares the actual date-time with the expected date-time of a date-time pattern.
+///
+/// Date-times without zone are treated as UTC.
+/// Returns `None` if the expected value or the actual format can only be evaluated by WireMock itself.
+pub(crate) fn matches<F>(expected: &str, options: &DateTimePatternOptions, actual: &str, is_match: F) -> Option<bool>
+    where F: FnOnce(Ordering) -> bool,
+{
+    let expected = expected_date_time(expected, options)?;
+    let actual_format = match options.actual_format() {
+        Some(actual_format) => Some(ActualFormat::parse(actual_format)?),
+        None => None,
+    };
+
+    let actual = match actual_format {
+        Some(actual_format) => actual_format.parse_date_time(actual.trim()),
+        None => parse_date_time(actual.trim()),
+    };
+    let actual = actual.and_then(|actual| truncate_optionally(actual, options.truncate_actual()));
+
+    Some(actual
+        .map(|actual| is_match(actual.cmp(&expected)))
+        .unwrap_or(false))
+}
+
+/// Like in WireMock, "now" is truncated before the offset is applied.
+fn expected_date_time(expected: &str, options: &DateTimePatternOptions) -> Option<DateTime<FixedOffset>> {
+    let expected = expected.trim();
+
+    match strip_prefix_ignore_case(expected, NOW) {
+        Some(offset) => {
+            let (amount, unit) = match options.expected_offset() {
+                Some(expected_offset) => expected_offset,
+                None => parse_offset(offset)?,
+            };
+            let now = truncate_optionally(Utc::now().fixed_offset(), options.truncate_expected())?;
+            add_offset(now, amount, unit)
+        },
+        None => truncate_optionally(parse_date_time(expected)?, options.truncate_expected()),
+    }
+}
+
+fn strip_prefix_ignore_case<'a>(value: &'a str, prefix: &str) -> Option<&'a str> {
+    if value.len() >= prefix.len() && value.is_char_boundary(prefix.len())
+        && value[..prefix.len()].eq_ignore_ascii_case(prefix) {
+        Some(&value[prefix.len()..])
+    } else {
+        None
+    }
+}
+
+/// Parses offsets like `+3 days` or `-15 seconds`.
+fn parse_offset(offset: &str) -> Option<(i64, DateTimeUnit)> {
+    let mut parts = offset.split_whitespace();
+    let (amount, unit) = match (parts.next(), parts.next(), parts.next()) {
+        (None, _, _) => return Some((0, DateTimeUnit::Seconds)),
+        (Some(amount), Some(unit), None) => (amount, unit),
+        _ => return None,
+    };
+
+    let amount = amount.trim_start_matches('+').parse::<i64>().ok()?;
+    let unit = match unit.to_lowercase().as_str() {
+        "seconds" => DateTimeUnit::Seconds,
+        "minutes" => DateTimeUnit::Minutes,
+        "hours" => DateTimeUnit::Hours,
+        "days" => DateTimeUnit::Days,
+        "months" => DateTimeUnit::Months,
+        "years" => DateTimeUnit::Years,
+        _ => return None,
+    };
+    Some((amount, unit))
+}
+
+fn add_offset(date_time: DateTime<FixedOffset>, amount: i64, unit: DateTimeUnit) -> Option<DateTime<FixedOffset>> {
+    let months = match unit {
+        DateTimeUnit::Seconds => return date_time.checked_add_signed(Duration::try_seconds(amount)?),
+        DateTimeUnit::Minutes => return date_time.checked_add_signed(Duration::try_minutes(amount)?),
+        DateTimeUnit::Hours => return date_time.checked_add_signed(Duration::try_hours(amount)?),
+        DateTimeUnit::Days => return date_time.checked_add_signed(Duration::try_days(amount)?),
+        DateTimeUnit::Months => amount,
+        DateTimeUnit::Years => amount.checked_mul(12)?,
+    };
+
+    let months = Months::new(u32::try_from(months.unsigned_abs()).ok()?);
+    if amount < 0 {
+        date_time.checked_sub_months(months)
+    } else {
+        date_time.checked_add_months(months)
+    }
+}
+
+/// Parses ISO 8601 date-times and dates as well as RFC 1123 date-times (as used in HTTP headers).
+fn parse_date_time(value: &str) -> Option<DateTime<FixedOffset>> {
+    DateTime::parse_from_rfc3339(value).ok()
+        .or_else(|| DateTime::parse_from_rfc2822(value).ok())
+        .or_else(|| NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f").ok()
+            .map(|date_time| date_time.and_utc().fixed_offset()))
+        .or_else(|| NaiveDate::parse_from_str(value, "%Y-%m-%d").ok()
+            .and_then(|date| date.and_hms_opt(0, 0, 0))
+            .map(|date_time| date_time.and_utc().fixed_offset()))
+}
+
+fn truncate_optionally(date_time: DateTime<FixedOffset>, truncation: Option<DateTimeTruncation>)
+                       -> Option<DateTime<FixedOffset>> {
+    match truncation {
+        Some(truncation) => truncate(date_time, truncation),
+        None => Some(date_time),
+    }
+}
+
+fn truncate(date_time: DateTime<FixedOffset>, truncation: DateTimeTruncation) -> Option<DateTime<FixedOffset>> {
+    let local = date_time.naive_local();
+    let first_day_of_month = NaiveDate::from_ymd_opt(local.year(), local.month(), 1)?;
+    let first_day_of_year = NaiveDate::from_ymd_opt(local.year(), 1, 1)?;
+
+    let truncated = match truncation {
+        DateTimeTruncation::FirstMinuteOfHour => local.date().and_hms_opt(local.hour(), 0, 0)?,
+        DateTimeTruncation::FirstHourOfDay => local.date().and_hms_opt(0, 0, 0)?,
+        DateTimeTruncation::FirstDayOfMonth => first_day_of_month.and_hms_opt(0, 0, 0)?,
+        DateTimeTruncation::FirstDayOfNextMonth => first_day_of_month
+            .checked_add_months(Months::new(1))?
+            .and_hms_opt(0, 0, 0)?,
+        DateTimeTruncation::LastDayOfMonth => first_day_of_month
+            .checked_add_months(Months::new(1))?
+            .pred_opt()?
+            .and_hms_opt(0, 0, 0)?,
+        DateTimeTruncation::FirstDayOfYear => first_day_of_year.and_hms_opt(0, 0, 0)?,
+        DateTimeTruncation::FirstDayOfNextYear => first_day_of_year
+            .checked_add_months(Months::new(12))?
+            .and_hms_opt(0, 0, 0)?,
+        DateTimeTruncation::LastDayOfYear => NaiveDate::from_ymd_opt(local.year(), 12, 31)?
+            .and_hms_opt(0, 0, 0)?,
+    };
+
+    date_time.offset().from_local_datetime(&truncated).single()
+}
+
+enum ActualFormat {
+    /// Seconds since epoch.
+    Unix,
+    /// Milliseconds since epoch.
+    Epoch,
+    /// A chrono format string.
+    Pattern(String),
+}
+
+impl ActualFormat {
+    /// Translates the Java `DateTimeFormatter` pattern, if all of its pattern letters are supported.
+    fn parse(actual_format: &str) -> Option<ActualFormat> {
+        match actual_format {
+            "unix" => return Some(ActualFormat::Unix),
+            "epoch" => return Some(ActualFormat::Epoch),
+            _ => {},
+        }
+
+        let chars = actual_format.chars().collect::<Vec<char>>();
+        let mut format = String::new();
+        let mut index = 0;
+
+        while index < chars.len() {
+            let letter = chars[index];
+            let count = chars[index..].iter().take_while(|c| **c == letter).count();
+            index += count;
+
+            let specifier = match (letter, count) {
+                ('\'', 2) => "'",
+                ('\'', 1) => {
+                    while index < chars.len() {
+                        match (chars[index], chars.get(index + 1)) {
+                            ('\'', Some('\'')) => {
+                                format.push('\'');
+                                index += 2;
+                            },
+                            ('\'', _) => break,
+                            (c, _) => {
+                                push_literal(&mut format, c);
+                                index += 1;
+                            },
+                        }
+                    }
+                    index += 1;
+                    continue;
+                },
+                ('y', 2) | ('u', 2) => "%y",
+                ('y', _) | ('u', _) => "%Y",
+                ('M', 1) => "%-m",
+                ('M', 2) => "%m",
+                ('M', 3) => "%b",
+                ('M', _) => "%B",
+                ('d', 1) => "%-d",
+                ('d', 2) => "%d",
+                ('H', 1) => "%-H",
+                ('H', 2) => "%H",
+                ('h', 1) => "%-I",
+                ('h', 2) => "%I",
+                ('m', 1) => "%-M",
+                ('m', 2) => "%M",
+                ('s', 1) => "%-S",
+                ('s', 2) => "%S",
+                ('S', 3) => "%3f",
+                ('S', 6) => "%6f",
+                ('S', 9) => "%9f",
+                ('a', 1) => "%p",
+                ('E', 1..=3) => "%a",
+                ('E', _) => "%A",
+                ('Z', 1..=3) | ('x', 1..=2) | ('X', 1..=2) => "%z",
+                ('x', 3) | ('X', 3) => "%:z",
+                (letter, _) if letter.is_ascii_alphabetic() => return None,
+                (literal, count) => {
+                    for _ in 0..count {
+                        push_literal(&mut format, literal);
+                    }
+                    continue;
+                },
+            };
+            format.push_str(specifier);
+        }
+
+        Some(ActualFormat::Pattern(format))
+    }
+
+    fn parse_date_time(&self, value: &str) -> Option<DateTime<FixedOffset>> {
+        match self {
+            ActualFormat::Unix => value.parse::<i64>().ok()
+                .and_then(|seconds| Utc.timestamp_opt(seconds, 0).single())
+                .map(|date_time| date_time.fixed_offset()),
+            ActualFormat::Epoch => value.parse::<i64>().ok()
+                .and_then(|millis| Utc.timestamp_millis_opt(millis).single())
+                .map(|date_time| date_time.fixed_offset()),
+            ActualFormat::Pattern(format) => DateTime::parse_from_str(value, format).ok()
+                .or_else(|| NaiveDateTime::parse_from_str(value, format).ok()
+                    .map(|date_time| date_time.and_utc().fixed_offset()))
+                .or_else(|| NaiveDate::parse_from_str(value, format).ok()
+                    .and_then(|date| date.and_hms_opt(0, 0, 0))
+                    .map(|date_time| date_time.and_utc().fixed_offset())),
+        }
+    }
+}
+
+fn push_literal(format: &mut String, literal: char) {
+    if literal == '%' {
+        format.push_str("%%");
+    } else {
+        format.push(literal);
+    }
+}
@@ -0,0 +1,226 @@
+use std::fmt;
+use std::time::SystemTime;
+
+use chrono::{DateTime, NaiveDateTime, SecondsFormat, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::matching::{ContentPattern, StringValuePattern};
+
+/// The expected date-time of a date-time pattern.
+///
+/// Either an ISO 8601 date-time, e.g. `2021-06-14T12:13:14Z`,
+/// or an offset from the current time, e.g. `now +3 days`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[serde(transparent)]
+pub struct ExpectedDateTime(String);
+
+impl ExpectedDateTime {
+    pub fn now() -> ExpectedDateTime {
+        ExpectedDateTime(String::from("now"))
+    }
+
+    /// The current time moved by the amount of units, e.g. `now -15 seconds`.
+    pub fn now_offset(amount: i64, unit: DateTimeUnit) -> ExpectedDateTime {
+        ExpectedDateTime(format!("now {:+} {}", amount, unit))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&str> for ExpectedDateTime {
+    fn from(expected: &str) -> ExpectedDateTime {
+        ExpectedDateTime(expected.to_string())
+    }
+}
+
+impl From<String> for ExpectedDateTime {
+    fn from(expected: String) -> ExpectedDateTime {
+        ExpectedDateTime(expected)
+    }
+}
+
+impl From<SystemTime> for ExpectedDateTime {
+    fn from(expected: SystemTime) -> ExpectedDateTime {
+        ExpectedDateTime::from(DateTime::<Utc>::from(expected))
+    }
+}
+
+impl<Tz> From<DateTime<Tz>> for ExpectedDateTime
+    where Tz: TimeZone,
+          Tz::Offset: fmt::Display,
+{
+    fn from(expected: DateTime<Tz>) -> ExpectedDateTime {
+        ExpectedDateTime(expected.to_rfc3339_opts(SecondsFormat::AutoSi, true))
+    }
+}
+
+impl From<NaiveDateTime> for ExpectedDateTime {
+    fn from(expected: NaiveDateTime) -> ExpectedDateTime {
+        ExpectedDateTime(expected.format("%Y-%m-%dT%H:%M:%S%.f").to_string())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum DateTimeUnit {
+    Seconds,
+    Minutes,
+    Hours,
+    Days,
+    Months,
+    Years,
+}
+
+impl fmt::Display for DateTimeUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            DateTimeUnit::Seconds => "seconds",
+            DateTimeUnit::Minutes => "minutes",
+            DateTimeUnit::Hours => "hours",
+            DateTimeUnit::Days => "days",
+            DateTimeUnit::Months => "months",
+            DateTimeUnit::Years => "years",
+        };
+        f.write_str(name)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone, Hash)]
+pub enum DateTimeTruncation {
+    #[serde(rename = "first minute of hour")]
+    FirstMinuteOfHour,
+    #[serde(rename = "first hour of day")]
+    FirstHourOfDay,
+    #[serde(rename = "first day of month")]
+    FirstDayOfMonth,
+    #[serde(rename = "first day of next month")]
+    FirstDayOfNextMonth,
+    #[serde(rename = "last day of month")]
+    LastDayOfMonth,
+    #[serde(rename = "first day of year")]
+    FirstDayOfYear,
+    #[serde(rename = "first day of next year")]
+    FirstDayOfNextYear,
+    #[serde(rename = "last day of year")]
+    LastDayOfYear,
+}
+
+/// Options shared by all date-time patterns.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+pub struct DateTimePatternOptions {
+    /// Java `DateTimeFormatter` pattern of the actual value, or `unix`/`epoch` for seconds/millis since epoch.
+    #[serde(rename = "actualFormat", skip_serializing_if = "Option::is_none")]
+    actual_format: Option<String>,
+    #[serde(rename = "truncateExpected", skip_serializing_if = "Option::is_none")]
+    truncate_expected: Option<DateTimeTruncation>,
+    #[serde(rename = "truncateActual", skip_serializing_if = "Option::is_none")]
+    truncate_actual: Option<DateTimeTruncation>,
+    #[serde(rename = "expectedOffset", skip_serializing_if = "Option::is_none")]
+    expected_offset: Option<i64>,
+    #[serde(rename = "expectedOffsetUnit", skip_serializing_if = "Option::is_none")]
+    expected_offset_unit: Option<DateTimeUnit>,
+}
+
+impl DateTimePatternOptions {
+    pub fn actual_format(&self) -> Option<&str> {
+        self.actual_format.as_deref()
+    }
+
+    pub fn truncate_expected(&self) -> Option<DateTimeTruncation> {
+        self.truncate_expected
+    }
+
+    pub fn truncate_actual(&self) -> Option<DateTimeTruncation> {
+        self.truncate_actual
+    }
+
+    /// The offset applied to an expected value relative to now.
+    pub fn expected_offset(&self) -> Option<(i64, DateTimeUnit)> {
+        match (self.expected_offset, self.expected_offset_unit) {
+            (Some(amount), Some(unit)) => Some((amount, unit)),
+            _ => None,
+        }
+    }
+}
+
+/// Implements the constructor, the option setters and the conversions shared by all date-time patterns.
+macro_rules! date_time_pattern {
+    ($pattern:ident, $expected:ident, $variant:ident) => {
+        impl $pattern {
+            pub fn new<E: Into<ExpectedDateTime>>(expected: E) -> $pattern {
+                $pattern {
+                    $expected: expected.into(),
+                    options: DateTimePatternOptions::default(),
+                }
+            }
+
+            pub fn with_actual_format<S: Into<String>>(mut self, actual_format: S) -> $pattern {
+                self.options.actual_format = Some(actual_format.into());
+                self
+            }
+
+            pub fn with_truncate_expected(mut self, truncation: DateTimeTruncation) -> $pattern {
+                self.options.truncate_expected = Some(truncation);
+                self
+            }
+
+            pub fn with_truncate_actual(mut self, truncation: DateTimeTruncation) -> $pattern {
+                self.options.truncate_actual = Some(truncation);
+                self
+            }
+
+            /// Moves an expected value of `now` by the amount of units.
+            pub fn with_expected_offset(mut self, amount: i64, unit: DateTimeUnit) -> $pattern {
+                self.options.expected_offset = Some(amount);
+                self.options.expected_offset_unit = Some(unit);
+                self
+            }
+
+            pub fn options(&self) -> &DateTimePatternOptions {
+                &self.options
+            }
+        }
+
+        impl StringValuePattern for $pattern {
+            fn value(&self) -> &str {
+                self.$expected.as_str()
+            }
+        }
+
+        impl From<$pattern> for ContentPattern {
+            fn from(pattern: $pattern) -> ContentPattern {
+                ContentPattern::$variant(pattern)
+            }
+        }
+    };
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+pub struct BeforeDateTimePattern {
+    before: ExpectedDateTime,
+    #[serde(flatten)]
+    options: DateTimePatternOptions,
+}
+
+date_time_pattern!(BeforeDateTimePattern, before, Before);
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+pub struct AfterDateTimePattern {
+    after: ExpectedDateTime,
+    #[serde(flatten)]
+    options: DateTimePatternOptions,
+}
+
+date_time_pattern!(AfterDateTimePattern, after, After);
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+pub struct EqualToDateTimePattern {
+    #[serde(rename = "equalToDateTime")]
+    equal_to_date_time: ExpectedDateTime,
+    #[serde(flatten)]
+    options: DateTimePatternOptions,
+}
+
+date_time_pattern!(EqualToDateTimePattern, equal_to_date_time, EqualToDateTime);
@@ -0,0 +1,362 @@
+use std::cmp::Ordering;
+
+use indexmap::IndexMap;
+use regex::Regex;
+use serde_json::Value;
+
+use crate::http::{MultiValue, RequestMethod};
+use crate::matching::{ContentPattern, EqualToXmlPattern, MatchResult, MultipartMatchingType, MultipartValuePattern};
+use crate::matching::{RequestPattern, StringValuePattern, UrlPattern};
+use crate::matching::{date_time_comparison, json_comparison, multipart, xml_comparison};
+use crate::verification::LoggedRequest;
+
+const URL_WEIGHT: f64 = 10f64;
+const METHOD_WEIGHT: f64 = 3f64;
+const DEFAULT_WEIGHT: f64 = 1f64;
+
+const DEFAULT_PLACEHOLDER_OPENING_DELIMITER_REGEX: &str = r"\$\{";
+const DEFAULT_PLACEHOLDER_CLOSING_DELIMITER_REGEX: &str = r"\}";
+
+/// Evaluates the request pattern locally against the request, without asking WireMock.
+///
+/// The distance is weighted the same way as in WireMock:
+/// the URL counts ten times and the method three times as much as headers, query parameters,
+/// cookies and the body.
+///
+/// Patterns that cannot be evaluated locally, e.g. a regex using Java only syntax,
+/// are treated as not matching.
+pub fn evaluate(request_pattern: &RequestPattern, request: &LoggedRequest) -> MatchResult {
+    let url_result = request_pattern.url_pattern()
+        .map(|url_pattern| match_url(url_pattern, request.url()))
+        .unwrap_or_else(|| Some(MatchResult::exact_match()));
+    let method_result = MatchResult::of(match_method(request_pattern.method(), request.method()));
+
+    let header_results = request_pattern.headers().iter()
+        .map(|(name, pattern)| match_multi_value(pattern, &header_values(request, name)));
+    let basic_auth_result = request_pattern.basic_auth_credentials()
+        .map(|credentials| {
+            let expected = format!("Basic {}",
+                base64::encode(&format!("{}:{}", credentials.username, credentials.password)));
+            let actual = request.first_header_str(http::header::AUTHORIZATION);
+            Some(MatchResult::of(actual == Some(expected.as_str())))
+        });
+    let headers_result = aggregate(header_results.chain(basic_auth_result));
+
+    let query_params_result = aggregate(request_pattern.query_params().iter()
+        .map(|(name, pattern)| match_multi_value(pattern, &multi_values(request.query_params(), name))));
+    let cookies_result = aggregate(request_pattern.cookies().iter()
+        .map(|(name, pattern)| match_multi_value(pattern, &multi_values(request.cookies(), name))));
+    let body_result = aggregate(request_pattern.body_patterns().iter()
+        .map(|pattern| match_body(pattern, request.body())));
+    let multipart_result = aggregate(request_pattern.multipart_patterns().iter()
+        .map(|pattern| match_multipart(pattern, request)));
+    // Request matcher extensions only exist in WireMock
+    let custom_matcher_result = request_pattern.custom_matcher()
+        .map(|_| MatchResult::no_match());
+    let inline_custom_matcher_result = request_pattern.inline_custom_matcher()
+        .map(|custom_matcher| MatchResult::of(custom_matcher.matches(request)));
+
+    MatchResult::aggregate_weighted(vec![
+        (or_no_match(url_result), URL_WEIGHT),
+        (method_result, METHOD_WEIGHT),
+        (headers_result, DEFAULT_WEIGHT),
+        (query_params_result, DEFAULT_WEIGHT),
+        (cookies_result, DEFAULT_WEIGHT),
+        (body_result, DEFAULT_WEIGHT),
+        (multipart_result, DEFAULT_WEIGHT),
+    ].into_iter()
+        .chain(custom_matcher_result.map(|result| (result, DEFAULT_WEIGHT)))
+        .chain(inline_custom_matcher_result.map(|result| (result, DEFAULT_WEIGHT))))
+}
+
+pub(crate) fn match_method(expected: &RequestMethod, actual: &RequestMethod) -> bool {
+    match expected {
+        RequestMethod::ANY => true,
+        expected => expected == actual,
+    }
+}
+
+/// Matches the request URL (path and query) against a URL pattern.
+///
+/// Returns `None` if the pattern can only be evaluated by WireMock itself.
+pub(crate) fn match_url(url_pattern: &UrlPattern, url: &str) -> Option<MatchResult> {
+    let path = url.split('?').next().unwrap_or(url);
+
+    match url_pattern {
+        UrlPattern::Url(expected) => Some(equal_to(expected, url)),
+        UrlPattern::UrlPath(expected) => Some(equal_to(expected, path)),
+        UrlPattern::UrlPathPattern(regex) => is_full_match(regex, path).map(MatchResult::of),
+        UrlPattern::UrlPattern(regex) => is_full_match(regex, url).map(MatchResult::of),
+    }
+}
+
+/// Matches all values of a header, query parameter or cookie against a pattern
+/// and returns the best match.
+///
+/// Returns `None` if the pattern can only be evaluated by WireMock itself.
+pub(crate) fn match_multi_value(pattern: &ContentPattern, values: &[String]) -> Option<MatchResult> {
+    if values.is_empty() {
+        return match_value(pattern, None);
+    }
+
+    best_result(values.iter()
+        .map(|value| match_value(pattern, Some(value))))
+}
+
+/// Matches a single (possibly absent) value against a pattern.
+///
+/// Returns `None` if the pattern can only be evaluated by WireMock itself.
+pub(crate) fn match_value(pattern: &ContentPattern, value: Option<&str>) -> Option<MatchResult> {
+    let value = match (pattern, value) {
+        (ContentPattern::And(pattern), value) => {
+            return all_results(pattern.operands().iter().map(|operand| match_value(operand, value)));
+        },
+        (ContentPattern::Or(pattern), value) => {
+            return best_result(pattern.operands().iter().map(|operand| match_value(operand, value)));
+        },
+        (ContentPattern::Absent(_), value) => return Some(MatchResult::of(value.is_none())),
+        (ContentPattern::Anything(_), _) => return Some(MatchResult::exact_match()),
+        (_, None) => return Some(MatchResult::no_match()),
+        (_, Some(value)) => value,
+    };
+
+    match pattern {
+        ContentPattern::BinaryEqualTo(pattern) => Some(MatchResult::of(pattern.bytes() == value.as_bytes())),
+        ContentPattern::Contains(pattern) => Some(MatchResult::of(value.contains(pattern.value()))),
+        ContentPattern::EqualTo(pattern) => {
+            if pattern.is_case_insensitive() {
+                Some(equal_to(&pattern.value().to_lowercase(), &value.to_lowercase()))
+            } else {
+                Some(equal_to(pattern.value(), value))
+            }
+        },
+        ContentPattern::EqualToJson(pattern) => {
+            let expected = serde_json::from_str::<Value>(pattern.value()).ok()?;
+            let actual = match serde_json::from_str::<Value>(value) {
+                Ok(actual) => actual,
+                Err(_) => return Some(MatchResult::no_match()),
+            };
+            let options = json_comparison::Options {
+                ignore_array_order: pattern.is_ignore_array_order(),
+                ignore_extra_elements: pattern.is_ignore_extra_elements(),
+            };
+            Some(MatchResult::partial_match(json_comparison::distance(&expected, &actual, options)))
+        },
+        ContentPattern::MatchesJsonPath(pattern) => {
+            let actual = match serde_json::from_str::<Value>(value) {
+                Ok(actual) => actual,
+                Err(_) => return Some(MatchResult::no_match()),
+            };
+            let nodes = jsonpath_lib::select(&actual, pattern.value()).ok()?;
+            if let Some(value_pattern) = pattern.value_pattern() {
+                return match_any_value(value_pattern, &json_path_values(&nodes));
+            }
+            let is_match = match nodes.as_slice() {
+                [] => false,
+                [Value::Null] => false,
+                [Value::Array(array)] => !array.is_empty(),
+                _ => true,
+            };
+            Some(MatchResult::of(is_match))
+        },
+        ContentPattern::EqualToXml(pattern) => {
+            let ignore_placeholder = ignore_placeholder(pattern)?;
+            xml_comparison::distance(pattern.value(), value, ignore_placeholder.as_ref())
+                .map(MatchResult::partial_match)
+        },
+        ContentPattern::MatchesXPath(pattern) => match pattern.value_pattern() {
+            Some(value_pattern) => {
+                let values = xml_comparison::xpath_values(pattern.value(), pattern.xpath_namespaces(), value)?;
+                match_any_value(value_pattern, &values)
+            },
+            None => xml_comparison::matches_xpath(pattern.value(), pattern.xpath_namespaces(), value)
+                .map(MatchResult::of),
+        },
+        ContentPattern::Regex(pattern) => is_full_match(pattern.value(), value).map(MatchResult::of),
+        ContentPattern::NegativeRegex(pattern) => is_full_match(pattern.value(), value)
+            .map(|is_match| MatchResult::of(!is_match)),
+        ContentPattern::Before(pattern) => {
+            date_time_comparison::matches(pattern.value(), pattern.options(), value, Ordering::is_lt)
+                .map(MatchResult::of)
+        },
+        ContentPattern::After(pattern) => {
+            date_time_comparison::matches(pattern.value(), pattern.options(), value, Ordering::is_gt)
+                .map(MatchResult::of)
+        },
+        ContentPattern::EqualToDateTime(pattern) => {
+            date_time_comparison::matches(pattern.value(), pattern.options(), value, Ordering::is_eq)
+                .map(MatchResult::of)
+        },
+        ContentPattern::Absent(_) | ContentPattern::Anything(_) => unreachable!(),
+        ContentPattern::And(_) | ContentPattern::Or(_) => unreachable!(),
+    }
+}
+
+/// Matches a request body against a pattern.
+///
+/// Returns `None` if the pattern can only be evaluated by WireMock itself.
+pub(crate) fn match_body(pattern: &ContentPattern, body: &[u8]) -> Option<MatchResult> {
+    match pattern {
+        ContentPattern::BinaryEqualTo(pattern) => Some(MatchResult::of(pattern.bytes() == body)),
+        ContentPattern::And(pattern) => {
+            all_results(pattern.operands().iter().map(|operand| match_body(operand, body)))
+        },
+        ContentPattern::Or(pattern) => {
+            best_result(pattern.operands().iter().map(|operand| match_body(operand, body)))
+        },
+        _ => match_value(pattern, Some(&String::from_utf8_lossy(body))),
+    }
+}
+
+/// Matches the parts of a multipart request against a pattern.
+///
+/// Returns `None` if the pattern can only be evaluated by WireMock itself.
+pub(crate) fn match_multipart(pattern: &MultipartValuePattern, request: &LoggedRequest) -> Option<MatchResult> {
+    let content_type = request.first_header_str(http::header::CONTENT_TYPE).unwrap_or_default();
+    let parts = match multipart::parse(content_type, request.body()) {
+        Some(parts) if !parts.is_empty() => parts,
+        _ => return Some(MatchResult::no_match()),
+    };
+
+    let results = parts.iter()
+        .map(|part| all_results(pattern.headers().iter()
+            .map(|(name, header_pattern)| match_multi_value(header_pattern, &part.header_values(name)))
+            .chain(pattern.body_patterns().iter()
+                .map(|body_pattern| match_body(body_pattern, part.body())))));
+
+    match pattern.matching_type() {
+        MultipartMatchingType::All => all_results(results),
+        MultipartMatchingType::Any => best_result(results),
+    }
+}
+
+/// Like Java's `Matcher.matches`: the regex has to match the whole value.
+///
+/// Returns `None` for regexes that are valid in Java, but not supported by the regex crate.
+pub(crate) fn is_full_match(regex: &str, value: &str) -> Option<bool> {
+    Regex::new(&format!("^(?:{})$", regex))
+        .ok()
+        .map(|regex| regex.is_match(value))
+}
+
+/// Like WireMock's `equalTo`: the normalized Levenshtein distance between the strings.
+fn equal_to(expected: &str, actual: &str) -> MatchResult {
+    if expected == actual {
+        return MatchResult::exact_match();
+    }
+
+    let expected = expected.chars().collect::<Vec<char>>();
+    let actual = actual.chars().collect::<Vec<char>>();
+
+    let mut previous_row = (0..=actual.len()).collect::<Vec<usize>>();
+    let mut current_row = vec![0; actual.len() + 1];
+    for (expected_index, expected_char) in expected.iter().enumerate() {
+        current_row[0] = expected_index + 1;
+        for (actual_index, actual_char) in actual.iter().enumerate() {
+            let substitution_cost = if expected_char == actual_char { 0 } else { 1 };
+            current_row[actual_index + 1] = (previous_row[actual_index] + substitution_cost)
+                .min(previous_row[actual_index + 1] + 1)
+                .min(current_row[actual_index] + 1);
+        }
+        std::mem::swap(&mut previous_row, &mut current_row);
+    }
+
+    let distance = previous_row[actual.len()] as f64;
+    MatchResult::partial_match(distance / expected.len().max(actual.len()) as f64)
+}
+
+/// The regex for `${xmlunit.ignore}` (or the custom delimited equivalent), if placeholders are enabled.
+///
+/// Returns `None` if the delimiter regexes are not supported by the regex crate.
+fn ignore_placeholder(pattern: &EqualToXmlPattern) -> Option<Option<Regex>> {
+    if !pattern.is_enable_placeholders() {
+        return Some(None);
+    }
+
+    let opening_delimiter = pattern.placeholder_opening_delimiter_regex()
+        .unwrap_or(DEFAULT_PLACEHOLDER_OPENING_DELIMITER_REGEX);
+    let closing_delimiter = pattern.placeholder_closing_delimiter_regex()
+        .unwrap_or(DEFAULT_PLACEHOLDER_CLOSING_DELIMITER_REGEX);
+
+    Regex::new(&format!(r"^\s*(?:{})\s*xmlunit\.ignore\s*(?:{})\s*$", opening_delimiter, closing_delimiter))
+        .ok()
+        .map(Some)
+}
+
+fn header_values(request: &LoggedRequest, name: &str) -> Vec<String> {
+    request.headers().get_all(name).iter()
+        .map(|value| String::from_utf8_lossy(value.as_bytes()).into_owned())
+        .collect()
+}
+
+fn multi_values<V: MultiValue>(multi_values: &IndexMap<String, V>, name: &str) -> Vec<String> {
+    multi_values.get(name)
+        .filter(|multi_value| multi_value.is_present())
+        .map(|multi_value| multi_value.values().to_vec())
+        .unwrap_or_default()
+}
+
+/// The aggregated result, or `None` if any result is unknown.
+fn all_results<I>(results: I) -> Option<MatchResult>
+    where I: IntoIterator<Item = Option<MatchResult>>,
+{
+    results.into_iter()
+        .collect::<Option<Vec<MatchResult>>>()
+        .map(MatchResult::aggregate)
+}
+
+/// Matches the results of a JSONPath or XPath expression against its value pattern.
+///
+/// There is no match if the expression selected nothing.
+fn match_any_value(value_pattern: &ContentPattern, values: &[String]) -> Option<MatchResult> {
+    if values.is_empty() {
+        return Some(MatchResult::no_match());
+    }
+
+    best_result(values.iter()
+        .map(|value| match_value(value_pattern, Some(value))))
+}
+
+/// The selected JSON values as strings: strings as they are, everything else as JSON.
+///
+/// A single selected array is matched as a whole and element by element.
+fn json_path_values(nodes: &[&Value]) -> Vec<String> {
+    let to_string = |node: &Value| match node {
+        Value::String(value) => value.clone(),
+        node => node.to_string(),
+    };
+
+    match nodes {
+        [Value::Null] => Vec::new(),
+        [Value::Array(array)] => std::iter::once(to_string(nodes[0]))
+            .chain(array.iter().map(to_string))
+            .collect(),
+        nodes => nodes.iter().map(|node| to_string(node)).collect(),
+    }
+}
+
+/// The closest result, or `None` if there is no exact match and any result is unknown.
+fn best_result<I>(results: I) -> Option<MatchResult>
+    where I: IntoIterator<Item = Option<MatchResult>>,
+{
+    let results = results.into_iter().collect::<Vec<Option<MatchResult>>>();
+
+    let best_result = results.iter()
+        .flatten()
+        .min_by(|left, right| left.distance().partial_cmp(&right.distance()).unwrap());
+    match best_result {
+        Some(best_result) if best_result.is_exact_match() => Some(*best_result),
+        _ if results.iter().any(Option::is_none) => None,
+        best_result => best_result.copied(),
+    }
+}
+
+fn aggregate<I>(results: I) -> MatchResult
+    where I: IntoIterator<Item = Option<MatchResult>>,
+{
+    MatchResult::aggregate(results.into_iter().map(or_no_match))
+}
+
+fn or_no_match(result: Option<MatchResult>) -> MatchResult {
+    result.unwrap_or_else(MatchResult::no_match)
+}
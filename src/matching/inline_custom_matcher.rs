@@ -0,0 +1,37 @@
+use std::fmt;
+use std::sync::Arc;
+
+use crate::verification::LoggedRequest;
+
+/// Request matcher evaluated by the client instead of WireMock.
+///
+/// It is not serialized, so WireMock only sees the rest of the request pattern.
+#[derive(Clone)]
+pub struct InlineCustomMatcher(Arc<dyn Fn(&LoggedRequest) -> bool + Send + Sync>);
+
+impl InlineCustomMatcher {
+    pub fn new<F>(matcher: F) -> InlineCustomMatcher
+        where F: Fn(&LoggedRequest) -> bool + Send + Sync + 'static,
+    {
+        InlineCustomMatcher(Arc::new(matcher))
+    }
+
+    pub fn matches(&self, request: &LoggedRequest) -> bool {
+        (self.0)(request)
+    }
+}
+
+/// Matchers are only equal if they are clones of each other, as closures cannot be compared.
+impl PartialEq for InlineCustomMatcher {
+    fn eq(&self, other: &InlineCustomMatcher) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for InlineCustomMatcher {}
+
+impl fmt::Debug for InlineCustomMatcher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("InlineCustomMatcher")
+    }
+}
@@ -0,0 +1,187 @@
+use serde_json::Value;
+
+use crate::matching::evaluation::is_full_match;
+
+const PLACEHOLDER_PREFIX: &str = "${json-unit.";
+const REGEX_PLACEHOLDER_PREFIX: &str = "${json-unit.regex}";
+
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct Options {
+    pub(crate) ignore_array_order: bool,
+    pub(crate) ignore_extra_elements: bool,
+}
+
+/// Number of differing nodes relative to the number of compared nodes.
+///
+/// Supports the JsonUnit placeholders WireMock understands, e.g. `${json-unit.any-string}`.
+pub(crate) fn distance(expected: &Value, actual: &Value, options: Options) -> f64 {
+    let comparison = compare(expected, actual, options);
+
+    if comparison.total == 0 {
+        0f64
+    } else {
+        comparison.differences as f64 / comparison.total as f64
+    }
+}
+
+#[derive(Debug, Default, Copy, Clone)]
+struct Comparison {
+    differences: usize,
+    total: usize,
+}
+
+impl Comparison {
+    fn equal(size: usize) -> Comparison {
+        Comparison {
+            differences: 0,
+            total: size,
+        }
+    }
+
+    fn different(size: usize) -> Comparison {
+        Comparison {
+            differences: size,
+            total: size,
+        }
+    }
+
+    fn add(&mut self, other: Comparison) {
+        self.differences += other.differences;
+        self.total += other.total;
+    }
+
+    fn is_equal(&self) -> bool {
+        self.differences == 0
+    }
+}
+
+fn compare(expected: &Value, actual: &Value, options: Options) -> Comparison {
+    if let Value::String(placeholder) = expected {
+        if let Some(is_match) = match_placeholder(placeholder, actual) {
+            return if is_match { Comparison::equal(1) } else { Comparison::different(1) };
+        }
+    }
+
+    match (expected, actual) {
+        (Value::Object(expected), Value::Object(actual)) => {
+            let mut comparison = Comparison::default();
+            for (key, expected_value) in expected {
+                match actual.get(key) {
+                    Some(actual_value) => comparison.add(compare(expected_value, actual_value, options)),
+                    None => comparison.add(Comparison::different(size(expected_value))),
+                }
+            }
+            if !options.ignore_extra_elements {
+                for (_, actual_value) in actual.iter().filter(|(key, _)| !expected.contains_key(*key)) {
+                    comparison.add(Comparison::different(size(actual_value)));
+                }
+            }
+            non_empty(comparison)
+        },
+        (Value::Array(expected), Value::Array(actual)) => {
+            let comparison = if options.ignore_array_order {
+                compare_unordered(expected, actual, options)
+            } else {
+                compare_ordered(expected, actual, options)
+            };
+            non_empty(comparison)
+        },
+        (Value::Number(expected), Value::Number(actual)) => {
+            if expected == actual || expected.as_f64() == actual.as_f64() {
+                Comparison::equal(1)
+            } else {
+                Comparison::different(1)
+            }
+        },
+        (expected, actual) => {
+            if expected == actual {
+                Comparison::equal(size(expected))
+            } else {
+                Comparison::different(size(expected))
+            }
+        },
+    }
+}
+
+fn compare_ordered(expected: &[Value], actual: &[Value], options: Options) -> Comparison {
+    let mut comparison = Comparison::default();
+
+    for (index, expected_value) in expected.iter().enumerate() {
+        match actual.get(index) {
+            Some(actual_value) => comparison.add(compare(expected_value, actual_value, options)),
+            None => comparison.add(Comparison::different(size(expected_value))),
+        }
+    }
+    if !options.ignore_extra_elements {
+        for actual_value in actual.iter().skip(expected.len()) {
+            comparison.add(Comparison::different(size(actual_value)));
+        }
+    }
+
+    comparison
+}
+
+fn compare_unordered(expected: &[Value], actual: &[Value], options: Options) -> Comparison {
+    let mut comparison = Comparison::default();
+    let mut used = vec![false; actual.len()];
+
+    for expected_value in expected {
+        let matching_index = actual.iter().enumerate()
+            .filter(|(index, _)| !used[*index])
+            .find(|(_, actual_value)| compare(expected_value, actual_value, options).is_equal())
+            .map(|(index, _)| index);
+
+        match matching_index {
+            Some(index) => {
+                used[index] = true;
+                comparison.add(Comparison::equal(size(expected_value)));
+            },
+            None => comparison.add(Comparison::different(size(expected_value))),
+        }
+    }
+    if !options.ignore_extra_elements {
+        for (_, actual_value) in actual.iter().enumerate().filter(|(index, _)| !used[*index]) {
+            comparison.add(Comparison::different(size(actual_value)));
+        }
+    }
+
+    comparison
+}
+
+/// Empty objects and arrays still count as one compared node.
+fn non_empty(comparison: Comparison) -> Comparison {
+    if comparison.total == 0 {
+        Comparison::equal(1)
+    } else {
+        comparison
+    }
+}
+
+fn size(value: &Value) -> usize {
+    let size = match value {
+        Value::Object(object) => object.values().map(size).sum(),
+        Value::Array(array) => array.iter().map(size).sum(),
+        _ => 1,
+    };
+    size.max(1)
+}
+
+fn match_placeholder(placeholder: &str, actual: &Value) -> Option<bool> {
+    if !placeholder.starts_with(PLACEHOLDER_PREFIX) {
+        return None;
+    }
+
+    if let Some(regex) = placeholder.strip_prefix(REGEX_PLACEHOLDER_PREFIX) {
+        return Some(actual.as_str()
+            .and_then(|actual| is_full_match(regex, actual))
+            .unwrap_or(false));
+    }
+
+    match placeholder {
+        "${json-unit.ignore}" => Some(true),
+        "${json-unit.any-string}" => Some(actual.is_string()),
+        "${json-unit.any-number}" => Some(actual.is_number()),
+        "${json-unit.any-boolean}" => Some(actual.is_boolean()),
+        _ => None,
+    }
+}
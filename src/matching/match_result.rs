@@ -1,11 +1,62 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq)]
 pub struct MatchResult {
     distance: f64,
 }
 
 impl MatchResult {
+    pub fn exact_match() -> MatchResult {
+        MatchResult {
+            distance: 0f64,
+        }
+    }
+
+    pub fn no_match() -> MatchResult {
+        MatchResult {
+            distance: 1f64,
+        }
+    }
+
+    /// A match with the given distance between `0.0` (exact match) and `1.0` (no match).
+    pub fn partial_match(distance: f64) -> MatchResult {
+        MatchResult {
+            distance: distance.clamp(0f64, 1f64),
+        }
+    }
+
+    pub fn of(is_match: bool) -> MatchResult {
+        if is_match {
+            MatchResult::exact_match()
+        } else {
+            MatchResult::no_match()
+        }
+    }
+
+    /// The average distance of all match results; an exact match if there are none.
+    pub fn aggregate<I>(match_results: I) -> MatchResult
+        where I: IntoIterator<Item = MatchResult>,
+    {
+        MatchResult::aggregate_weighted(match_results.into_iter()
+            .map(|match_result| (match_result, 1f64)))
+    }
+
+    /// The weighted average distance of all match results; an exact match if there are none.
+    pub fn aggregate_weighted<I>(weighted_match_results: I) -> MatchResult
+        where I: IntoIterator<Item = (MatchResult, f64)>,
+    {
+        let (weighted_distance, total_weight) = weighted_match_results.into_iter()
+            .fold((0f64, 0f64), |(weighted_distance, total_weight), (match_result, weight)| {
+                (weighted_distance + match_result.distance * weight, total_weight + weight)
+            });
+
+        if total_weight == 0f64 {
+            MatchResult::exact_match()
+        } else {
+            MatchResult::partial_match(weighted_distance / total_weight)
+        }
+    }
+
     pub fn distance(&self) -> f64 {
         self.distance
     }
@@ -1,13 +1,27 @@
 pub use crate::matching::request_pattern::*;
 pub use crate::matching::url_pattern::*;
 pub use crate::matching::content_pattern::*;
+pub use crate::matching::date_time_pattern::*;
+pub use crate::matching::multipart_value_pattern::*;
+pub use crate::matching::custom_matcher_definition::*;
+pub use crate::matching::inline_custom_matcher::*;
 pub use crate::matching::builder::*;
 pub use crate::matching::match_result::*;
 pub use crate::matching::count::*;
+pub use crate::matching::evaluation::evaluate;
 
 mod request_pattern;
 mod url_pattern;
 mod content_pattern;
+mod date_time_pattern;
+mod multipart_value_pattern;
+mod custom_matcher_definition;
+mod inline_custom_matcher;
 mod builder;
 mod match_result;
 mod count;
+pub(crate) mod evaluation;
+mod date_time_comparison;
+mod json_comparison;
+mod multipart;
+mod xml_comparison;
@@ -0,0 +1,97 @@
+/// A part of a `multipart/*` request body.
+pub(crate) struct Part<'a> {
+    headers: Vec<(String, String)>,
+    body: &'a [u8],
+}
+
+impl<'a> Part<'a> {
+    /// All values of the header (case insensitive).
+    pub(crate) fn header_values(&self, name: &str) -> Vec<String> {
+        self.headers.iter()
+            .filter(|(header_name, _)| header_name.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.clone())
+            .collect()
+    }
+
+    pub(crate) fn body(&self) -> &'a [u8] {
+        self.body
+    }
+}
+
+/// Splits the body into its parts.
+///
+/// Returns `None` if the content type is not multipart or has no boundary.
+pub(crate) fn parse<'a>(content_type: &str, body: &'a [u8]) -> Option<Vec<Part<'a>>> {
+    let boundary = boundary(content_type)?;
+    let delimiter = format!("--{}", boundary).into_bytes();
+
+    let mut parts = Vec::new();
+    let mut remaining = &body[find(body, &delimiter)? + delimiter.len()..];
+
+    loop {
+        if remaining.starts_with(b"--") {
+            break;
+        }
+        let end = match find(remaining, &delimiter) {
+            Some(end) => end,
+            None => break,
+        };
+
+        let part = strip_line_break_suffix(strip_line_break_prefix(&remaining[..end]));
+        parts.push(parse_part(part));
+        remaining = &remaining[end + delimiter.len()..];
+    }
+
+    Some(parts)
+}
+
+fn boundary(content_type: &str) -> Option<&str> {
+    let mut params = content_type.split(';');
+    let mime_type = params.next()?.trim();
+    if !mime_type.to_ascii_lowercase().starts_with("multipart/") {
+        return None;
+    }
+
+    params
+        .filter_map(|param| param.split_once('='))
+        .find(|(name, _)| name.trim().eq_ignore_ascii_case("boundary"))
+        .map(|(_, value)| value.trim().trim_matches('"'))
+        .filter(|boundary| !boundary.is_empty())
+}
+
+fn parse_part(part: &[u8]) -> Part<'_> {
+    let (header_bytes, body) = match find(part, b"\r\n\r\n") {
+        Some(index) => (&part[..index], &part[index + 4..]),
+        None => match find(part, b"\n\n") {
+            Some(index) => (&part[..index], &part[index + 2..]),
+            None => (&part[..0], part),
+        },
+    };
+
+    let headers = String::from_utf8_lossy(header_bytes).lines()
+        .filter_map(|line| line.split_once(':'))
+        .map(|(name, value)| (name.trim().to_string(), value.trim().to_string()))
+        .collect();
+
+    Part {
+        headers,
+        body,
+    }
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len())
+        .position(|window| window == needle)
+}
+
+fn strip_line_break_prefix(bytes: &[u8]) -> &[u8] {
+    bytes.strip_prefix(b"\r\n")
+        .or_else(|| bytes.strip_prefix(b"\n"))
+        .unwrap_or(bytes)
+}
+
+fn strip_line_break_suffix(bytes: &[u8]) -> &[u8] {
+    bytes.strip_suffix(b"\r\n")
+        .or_else(|| bytes.strip_suffix(b"\n"))
+        .unwrap_or(bytes)
+}
@@ -0,0 +1,62 @@
+use std::fmt;
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+use crate::matching::ContentPattern;
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct MultipartValuePattern {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) name: Option<String>,
+    #[serde(rename = "matchingType")]
+    pub(crate) matching_type: MultipartMatchingType,
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub(crate) headers: IndexMap<String, ContentPattern>,
+    #[serde(rename = "bodyPatterns", default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) body_patterns: Vec<ContentPattern>,
+}
+
+impl MultipartValuePattern {
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    pub fn matching_type(&self) -> MultipartMatchingType {
+        self.matching_type
+    }
+
+    pub fn headers(&self) -> &IndexMap<String, ContentPattern> {
+        &self.headers
+    }
+
+    pub fn body_patterns(&self) -> &[ContentPattern] {
+        &self.body_patterns
+    }
+
+    pub fn is_match_any(&self) -> bool {
+        self.matching_type == MultipartMatchingType::Any
+    }
+
+    pub fn is_match_all(&self) -> bool {
+        self.matching_type == MultipartMatchingType::All
+    }
+}
+
+/// Whether any or all parts of a multipart request have to match the pattern.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone, Hash)]
+pub enum MultipartMatchingType {
+    #[serde(rename = "ANY")]
+    Any,
+    #[serde(rename = "ALL")]
+    All,
+}
+
+impl fmt::Display for MultipartMatchingType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MultipartMatchingType::Any => f.write_str("ANY"),
+            MultipartMatchingType::All => f.write_str("ALL"),
+        }
+    }
+}
@@ -7,14 +7,15 @@ use serde::{Deserialize, Serialize};
 use crate::any_url;
 use crate::client::BasicCredentials;
 use crate::http::RequestMethod;
-use crate::matching::{ContentPattern, RequestPatternBuilder, UrlPattern};
+use crate::matching::{ContentPattern, CustomMatcherDefinition, InlineCustomMatcher, MultipartValuePattern, RequestPatternBuilder, UrlPattern};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct RequestPattern {
     /// The url pattern to match exactly against.
     #[serde(flatten)]
     pub(crate) url_pattern: Option<UrlPattern>,
     /// The HTTP request method e.g. GET
+    #[serde(default = "crate::serde::default_method")]
     pub(crate) method: RequestMethod,
     /// Query parameter patterns to match against.
     #[serde(rename = "queryParameters", default, skip_serializing_if = "IndexMap::is_empty")]
@@ -31,6 +32,15 @@ pub struct RequestPattern {
     /// Request body patterns to match against.
     #[serde(rename = "bodyPatterns", default, skip_serializing_if = "Vec::is_empty")]
     pub(crate) body_patterns: Vec<ContentPattern>,
+    /// Multipart request body patterns to match against.
+    #[serde(rename = "multipartPatterns", default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) multipart_patterns: Vec<MultipartValuePattern>,
+    /// Request matcher extension to match against.
+    #[serde(rename = "customMatcher", skip_serializing_if = "Option::is_none")]
+    pub(crate) custom_matcher: Option<CustomMatcherDefinition>,
+    /// Request matcher evaluated by the client.
+    #[serde(skip)]
+    pub(crate) inline_custom_matcher: Option<InlineCustomMatcher>,
 }
 
 impl RequestPattern {
@@ -66,9 +76,20 @@ impl RequestPattern {
         &self.body_patterns
     }
 
+    pub fn multipart_patterns(&self) -> &[MultipartValuePattern] {
+        &self.multipart_patterns
+    }
+
+    pub fn custom_matcher(&self) -> Option<&CustomMatcherDefinition> {
+        self.custom_matcher.as_ref()
+    }
+
+    pub fn inline_custom_matcher(&self) -> Option<&InlineCustomMatcher> {
+        self.inline_custom_matcher.as_ref()
+    }
+
     pub fn has_inline_custom_matcher(&self) -> bool {
-        // Not yet implemented
-        false
+        self.inline_custom_matcher.is_some()
     }
 }
 
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
 pub enum UrlPattern {
     /// The path and query to match exactly against.
     #[serde(rename = "url")]
@@ -14,8 +15,6 @@ pub enum UrlPattern {
     /// The path and query regex to match against.
     #[serde(rename = "urlPattern")]
     UrlPattern(String),
-    #[doc(hidden)]
-    __Nonexhaustive,
 }
 
 impl UrlPattern {
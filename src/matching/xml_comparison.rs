@@ -0,0 +1,203 @@
+use indexmap::IndexMap;
+use regex::Regex;
+use sxd_document::dom::{ChildOfElement, ChildOfRoot, Element};
+use sxd_document::parser;
+use sxd_xpath::{Context, Factory, Value};
+
+/// Number of differing nodes (elements, attributes and text) relative to the number of compared nodes.
+///
+/// Whitespace between elements and the order of attributes are ignored.
+/// Returns `None` if the expected XML is invalid.
+pub(crate) fn distance(expected: &str, actual: &str, ignore_placeholder: Option<&Regex>) -> Option<f64> {
+    let expected_package = parser::parse(expected).ok()?;
+    let expected_document = expected_package.as_document();
+    let expected_root = root_element(expected_document.root().children())?;
+
+    let actual_package = match parser::parse(actual) {
+        Ok(actual_package) => actual_package,
+        Err(_) => return Some(1f64),
+    };
+    let actual_document = actual_package.as_document();
+    let actual_root = match root_element(actual_document.root().children()) {
+        Some(actual_root) => actual_root,
+        None => return Some(1f64),
+    };
+
+    let comparison = compare_elements(expected_root, actual_root, ignore_placeholder);
+    Some(comparison.differences as f64 / comparison.total.max(1) as f64)
+}
+
+/// Whether the XPath expression selects at least one node (or evaluates to `true`) in the XML.
+///
+/// Returns `None` if the XPath expression is invalid.
+pub(crate) fn matches_xpath(xpath: &str, namespaces: &IndexMap<String, String>, xml: &str) -> Option<bool> {
+    evaluate_xpath(xpath, namespaces, xml, |value| match value {
+        Some(Value::Nodeset(nodes)) => nodes.size() > 0,
+        Some(Value::Boolean(value)) => value,
+        Some(Value::Number(value)) => !value.is_nan(),
+        Some(Value::String(value)) => !value.is_empty(),
+        None => false,
+    })
+}
+
+/// The string values of the nodes selected by the XPath expression, in document order.
+///
+/// Returns `None` if the expression is not supported.
+pub(crate) fn xpath_values(xpath: &str, namespaces: &IndexMap<String, String>, xml: &str) -> Option<Vec<String>> {
+    evaluate_xpath(xpath, namespaces, xml, |value| match value {
+        Some(Value::Nodeset(nodes)) => nodes.document_order().iter()
+            .map(|node| node.string_value())
+            .collect(),
+        Some(Value::Boolean(value)) => vec![value.to_string()],
+        Some(Value::Number(value)) => vec![value.to_string()],
+        Some(Value::String(value)) => vec![value],
+        None => Vec::new(),
+    })
+}
+
+/// Evaluates the XPath expression and passes the result, or `None` if the XML is invalid or the evaluation fails.
+fn evaluate_xpath<T, F>(xpath: &str, namespaces: &IndexMap<String, String>, xml: &str, f: F) -> Option<T>
+    where F: FnOnce(Option<Value<'_>>) -> T,
+{
+    let xpath = Factory::new().build(xpath).ok()??;
+
+    let package = match parser::parse(xml) {
+        Ok(package) => package,
+        Err(_) => return Some(f(None)),
+    };
+    let document = package.as_document();
+
+    let mut context = Context::new();
+    for (prefix, uri) in namespaces {
+        context.set_namespace(prefix, uri);
+    }
+
+    Some(f(xpath.evaluate(&context, document.root()).ok()))
+}
+
+#[derive(Debug, Default, Copy, Clone)]
+struct Comparison {
+    differences: usize,
+    total: usize,
+}
+
+impl Comparison {
+    fn of(is_equal: bool) -> Comparison {
+        Comparison {
+            differences: if is_equal { 0 } else { 1 },
+            total: 1,
+        }
+    }
+
+    fn different(size: usize) -> Comparison {
+        Comparison {
+            differences: size,
+            total: size,
+        }
+    }
+
+    fn add(&mut self, other: Comparison) {
+        self.differences += other.differences;
+        self.total += other.total;
+    }
+}
+
+enum Node<'d> {
+    Element(Element<'d>),
+    Text(String),
+}
+
+fn root_element(children: Vec<ChildOfRoot<'_>>) -> Option<Element<'_>> {
+    children.into_iter()
+        .find_map(|child| match child {
+            ChildOfRoot::Element(element) => Some(element),
+            _ => None,
+        })
+}
+
+/// Child elements and non-blank text, with adjacent text nodes joined.
+fn significant_children(element: Element<'_>) -> Vec<Node<'_>> {
+    let mut nodes = Vec::new();
+    let mut text = String::new();
+
+    for child in element.children() {
+        match child {
+            ChildOfElement::Element(child) => {
+                push_text(&mut nodes, &mut text);
+                nodes.push(Node::Element(child));
+            },
+            ChildOfElement::Text(child) => text.push_str(child.text()),
+            ChildOfElement::Comment(_) | ChildOfElement::ProcessingInstruction(_) => {},
+        }
+    }
+    push_text(&mut nodes, &mut text);
+
+    nodes
+}
+
+fn push_text(nodes: &mut Vec<Node<'_>>, text: &mut String) {
+    let trimmed = text.trim();
+    if !trimmed.is_empty() {
+        nodes.push(Node::Text(trimmed.to_string()));
+    }
+    text.clear();
+}
+
+fn size(node: &Node<'_>) -> usize {
+    match node {
+        Node::Element(element) => {
+            1 + element.attributes().len() + significant_children(*element).iter().map(size).sum::<usize>()
+        },
+        Node::Text(_) => 1,
+    }
+}
+
+fn compare_elements(expected: Element<'_>, actual: Element<'_>, ignore_placeholder: Option<&Regex>) -> Comparison {
+    if expected.name() != actual.name() {
+        return Comparison::different(size(&Node::Element(expected)));
+    }
+
+    let mut comparison = Comparison::of(true);
+
+    let expected_attributes = expected.attributes();
+    let actual_attributes = actual.attributes();
+    for expected_attribute in &expected_attributes {
+        let actual_value = actual_attributes.iter()
+            .find(|actual_attribute| actual_attribute.name() == expected_attribute.name())
+            .map(|actual_attribute| actual_attribute.value());
+        let is_equal = actual_value
+            .map(|actual_value| text_equals(expected_attribute.value(), actual_value, ignore_placeholder))
+            .unwrap_or(false);
+        comparison.add(Comparison::of(is_equal));
+    }
+    for _ in actual_attributes.iter()
+        .filter(|actual_attribute| expected_attributes.iter().all(|expected| expected.name() != actual_attribute.name())) {
+        comparison.add(Comparison::of(false));
+    }
+
+    let expected_children = significant_children(expected);
+    let actual_children = significant_children(actual);
+    for (index, expected_child) in expected_children.iter().enumerate() {
+        let child_comparison = match (expected_child, actual_children.get(index)) {
+            (Node::Element(expected_child), Some(Node::Element(actual_child))) => {
+                compare_elements(*expected_child, *actual_child, ignore_placeholder)
+            },
+            (Node::Text(expected_text), Some(Node::Text(actual_text))) => {
+                Comparison::of(text_equals(expected_text, actual_text, ignore_placeholder))
+            },
+            (expected_child, _) => Comparison::different(size(expected_child)),
+        };
+        comparison.add(child_comparison);
+    }
+    for actual_child in actual_children.iter().skip(expected_children.len()) {
+        comparison.add(Comparison::different(size(actual_child)));
+    }
+
+    comparison
+}
+
+fn text_equals(expected: &str, actual: &str, ignore_placeholder: Option<&Regex>) -> bool {
+    expected == actual || ignore_placeholder
+        .map(|ignore_placeholder| ignore_placeholder.is_match(expected))
+        .unwrap_or(false)
+}
@@ -2,7 +2,7 @@ use serde::{Serialize, Deserialize};
 
 use crate::global::GlobalSettings;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct GetGlobalSettingsResult {
     settings: GlobalSettings,
 }
@@ -13,8 +13,8 @@ impl GetGlobalSettingsResult {
     }
 }
 
-impl Into<GlobalSettings> for GetGlobalSettingsResult {
-    fn into(self) -> GlobalSettings {
-        self.settings
+impl From<GetGlobalSettingsResult> for GlobalSettings {
+    fn from(result: GetGlobalSettingsResult) -> GlobalSettings {
+        result.settings
     }
 }
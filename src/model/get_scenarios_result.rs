@@ -2,7 +2,7 @@ use serde::{Serialize, Deserialize};
 
 use crate::stubbing::Scenario;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct GetScenariosResult {
     scenarios: Vec<Scenario>,
 }
@@ -13,8 +13,8 @@ impl GetScenariosResult {
     }
 }
 
-impl Into<Vec<Scenario>> for GetScenariosResult {
-    fn into(self) -> Vec<Scenario> {
-        self.scenarios
+impl From<GetScenariosResult> for Vec<Scenario> {
+    fn from(result: GetScenariosResult) -> Vec<Scenario> {
+        result.scenarios
     }
 }
@@ -3,7 +3,7 @@ use serde::{Serialize, Deserialize};
 use crate::model::pagination::{Meta, PaginatedResult};
 use crate::stubbing::ServeEvent;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct GetServeEventsResult {
     requests: Vec<ServeEvent>,
     meta: Meta,
@@ -27,8 +27,8 @@ impl GetServeEventsResult {
     }
 }
 
-impl Into<Vec<ServeEvent>> for GetServeEventsResult {
-    fn into(self) -> Vec<ServeEvent> {
-        self.requests
+impl From<GetServeEventsResult> for Vec<ServeEvent> {
+    fn from(result: GetServeEventsResult) -> Vec<ServeEvent> {
+        result.requests
     }
 }
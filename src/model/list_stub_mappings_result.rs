@@ -3,7 +3,7 @@ use serde::{Serialize, Deserialize};
 use crate::model::pagination::{Meta, PaginatedResult};
 use crate::stubbing::StubMapping;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct ListStubMappingsResult {
     mappings: Vec<StubMapping>,
     meta: Meta,
@@ -25,8 +25,8 @@ impl ListStubMappingsResult {
     }
 }
 
-impl Into<Vec<StubMapping>> for ListStubMappingsResult {
-    fn into(self) -> Vec<StubMapping> {
-        self.mappings
+impl From<ListStubMappingsResult> for Vec<StubMapping> {
+    fn from(result: ListStubMappingsResult) -> Vec<StubMapping> {
+        result.mappings
     }
 }
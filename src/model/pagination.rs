@@ -1,10 +1,55 @@
 use std::fmt::Debug;
+use std::time::SystemTime;
+
 use serde::{Serialize, Deserialize};
 
-#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+/// Query parameters to select a page of a paginated admin resource.
+#[derive(Debug, Default, Copy, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub struct PageParams {
-    limit: u16,
-    offset: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    offset: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "crate::serde::optional_rfc3339")]
+    since: Option<SystemTime>,
+}
+
+impl PageParams {
+    pub fn new(limit: u32, offset: u32) -> PageParams {
+        PageParams {
+            limit: Some(limit),
+            offset: Some(offset),
+            since: None,
+        }
+    }
+
+    pub fn with_limit(mut self, limit: u32) -> PageParams {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn with_offset(mut self, offset: u32) -> PageParams {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Only select items that were logged after the given time.
+    pub fn with_since(mut self, since: SystemTime) -> PageParams {
+        self.since = Some(since);
+        self
+    }
+
+    pub fn limit(&self) -> Option<u32> {
+        self.limit
+    }
+
+    pub fn offset(&self) -> Option<u32> {
+        self.offset
+    }
+
+    pub fn since(&self) -> Option<SystemTime> {
+        self.since
+    }
 }
 
 pub trait PaginatedResult<T>: Debug + Serialize + Deserialize<'static> {
@@ -12,13 +57,13 @@ pub trait PaginatedResult<T>: Debug + Serialize + Deserialize<'static> {
     fn meta(&self) -> &Meta;
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
 pub struct Meta {
-    total: u16,
+    total: u32,
 }
 
 impl Meta {
-    pub fn total(&self) -> u16 {
+    pub fn total(&self) -> u32 {
         self.total
     }
 }
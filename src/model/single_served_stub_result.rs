@@ -3,7 +3,7 @@ use serde::{Serialize, Deserialize};
 use crate::stubbing::ServeEvent;
 use crate::model::SingleItemResult;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct SingleServedStubResult {
     #[serde(flatten)]
     serve_event: ServeEvent,
@@ -15,8 +15,8 @@ impl SingleItemResult<ServeEvent> for SingleServedStubResult {
     }
 }
 
-impl Into<ServeEvent> for SingleServedStubResult {
-    fn into(self) -> ServeEvent {
-        self.serve_event
+impl From<SingleServedStubResult> for ServeEvent {
+    fn from(result: SingleServedStubResult) -> ServeEvent {
+        result.serve_event
     }
 }
@@ -3,7 +3,7 @@ use serde::{Serialize, Deserialize};
 use crate::stubbing::StubMapping;
 use crate::model::SingleItemResult;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct SingleStubMappingResult {
     #[serde(flatten)]
     stub_mapping: StubMapping,
@@ -15,8 +15,8 @@ impl SingleItemResult<StubMapping> for SingleStubMappingResult {
     }
 }
 
-impl Into<StubMapping> for SingleStubMappingResult {
-    fn into(self) -> StubMapping {
-        self.stub_mapping
+impl From<SingleStubMappingResult> for StubMapping {
+    fn from(result: SingleStubMappingResult) -> StubMapping {
+        result.stub_mapping
     }
 }
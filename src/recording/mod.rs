@@ -0,0 +1,15 @@
+pub use crate::recording::record_spec::{
+    record_spec,
+    CaptureHeadersSpec,
+    ProxiedServeEventFilters,
+    RecordSpec,
+    RecordSpecBuilder,
+    RequestBodyPatternFactory,
+    ResponseDefinitionBodyMatcher,
+};
+pub use crate::recording::recording_status::{RecordingStatus, RecordingStatusResult};
+pub use crate::recording::snapshot_record_result::SnapshotRecordResult;
+
+mod record_spec;
+mod recording_status;
+mod snapshot_record_result;
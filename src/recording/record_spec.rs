@@ -0,0 +1,331 @@
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::extension::Parameters;
+use crate::matching::RequestPattern;
+
+pub fn record_spec() -> RecordSpecBuilder {
+    RecordSpecBuilder::new()
+}
+
+/// Describes how requests proxied by WireMock are turned into stub mappings
+/// when recording or taking a snapshot.
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RecordSpec {
+    /// Target URL when using the record and playback API.
+    #[serde(rename = "targetBaseUrl", skip_serializing_if = "Option::is_none")]
+    target_base_url: Option<String>,
+    /// Filters used to determine which requests to include in the snapshot.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    filters: Option<ProxiedServeEventFilters>,
+    /// Headers from the request to include in the generated stub mappings.
+    #[serde(rename = "captureHeaders", default, skip_serializing_if = "IndexMap::is_empty")]
+    capture_headers: IndexMap<String, CaptureHeadersSpec>,
+    /// Factory for the request body pattern matcher in the generated stub mappings.
+    #[serde(rename = "requestBodyPattern", skip_serializing_if = "Option::is_none")]
+    request_body_pattern: Option<RequestBodyPatternFactory>,
+    /// Criteria for extracting response bodies to a separate file instead of including it
+    /// in the stub mapping.
+    #[serde(rename = "extractBodyCriteria", skip_serializing_if = "Option::is_none")]
+    extract_body_criteria: Option<ResponseDefinitionBodyMatcher>,
+    /// Whether to save stub mappings to the file system or just return them.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    persist: Option<bool>,
+    /// When true, duplicate requests will be added to a scenario.
+    /// When false, only the first instance will be recorded.
+    #[serde(rename = "repeatsAsScenarios", skip_serializing_if = "Option::is_none")]
+    repeats_as_scenarios: Option<bool>,
+    /// Names of stub mapping transformer extensions to apply to the generated stub mappings.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    transformers: Vec<String>,
+    /// Parameters to pass to the stub mapping transformers.
+    #[serde(rename = "transformerParameters", default, skip_serializing_if = "Parameters::is_empty")]
+    transformer_parameters: Parameters,
+}
+
+impl RecordSpec {
+    pub fn target_base_url(&self) -> Option<&str> {
+        self.target_base_url.as_deref()
+    }
+
+    pub(crate) fn has_inline_custom_matcher(&self) -> bool {
+        self.filters()
+            .and_then(ProxiedServeEventFilters::request_pattern)
+            .is_some_and(RequestPattern::has_inline_custom_matcher)
+    }
+
+    pub fn filters(&self) -> Option<&ProxiedServeEventFilters> {
+        self.filters.as_ref()
+    }
+
+    pub fn capture_headers(&self) -> &IndexMap<String, CaptureHeadersSpec> {
+        &self.capture_headers
+    }
+
+    pub fn request_body_pattern(&self) -> Option<&RequestBodyPatternFactory> {
+        self.request_body_pattern.as_ref()
+    }
+
+    pub fn extract_body_criteria(&self) -> Option<&ResponseDefinitionBodyMatcher> {
+        self.extract_body_criteria.as_ref()
+    }
+
+    pub fn persist(&self) -> Option<bool> {
+        self.persist
+    }
+
+    pub fn repeats_as_scenarios(&self) -> Option<bool> {
+        self.repeats_as_scenarios
+    }
+
+    pub fn transformers(&self) -> &[String] {
+        &self.transformers
+    }
+
+    pub fn transformer_parameters(&self) -> &Parameters {
+        &self.transformer_parameters
+    }
+}
+
+impl From<RecordSpecBuilder> for RecordSpec {
+    fn from(builder: RecordSpecBuilder) -> RecordSpec {
+        builder.build()
+    }
+}
+
+impl From<&str> for RecordSpec {
+    fn from(target_base_url: &str) -> RecordSpec {
+        record_spec().for_target(target_base_url).build()
+    }
+}
+
+impl From<String> for RecordSpec {
+    fn from(target_base_url: String) -> RecordSpec {
+        record_spec().for_target(target_base_url).build()
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ProxiedServeEventFilters {
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    filters: Option<RequestPattern>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ids: Option<Vec<Uuid>>,
+    #[serde(rename = "allowNonProxied", default)]
+    allow_non_proxied: bool,
+}
+
+impl ProxiedServeEventFilters {
+    pub fn request_pattern(&self) -> Option<&RequestPattern> {
+        self.filters.as_ref()
+    }
+
+    pub fn ids(&self) -> Option<&[Uuid]> {
+        self.ids.as_deref()
+    }
+
+    pub fn allow_non_proxied(&self) -> bool {
+        self.allow_non_proxied
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct CaptureHeadersSpec {
+    #[serde(rename = "caseInsensitive", skip_serializing_if = "Option::is_none")]
+    case_insensitive: Option<bool>,
+}
+
+impl CaptureHeadersSpec {
+    pub fn case_insensitive(&self) -> Option<bool> {
+        self.case_insensitive
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq)]
+#[serde(tag = "matcher")]
+#[non_exhaustive]
+pub enum RequestBodyPatternFactory {
+    #[serde(rename = "auto")]
+    Auto {
+        #[serde(rename = "ignoreArrayOrder")]
+        ignore_array_order: bool,
+        #[serde(rename = "ignoreExtraElements")]
+        ignore_extra_elements: bool,
+        #[serde(rename = "caseInsensitive")]
+        case_insensitive: bool,
+    },
+    #[serde(rename = "equalToJson")]
+    EqualToJson {
+        #[serde(rename = "ignoreArrayOrder")]
+        ignore_array_order: bool,
+        #[serde(rename = "ignoreExtraElements")]
+        ignore_extra_elements: bool,
+    },
+    #[serde(rename = "equalToXml")]
+    EqualToXml,
+    #[serde(rename = "equalTo")]
+    EqualTo {
+        #[serde(rename = "caseInsensitive")]
+        case_insensitive: bool,
+    },
+}
+
+/// Size thresholds in bytes above which response bodies are extracted to separate files.
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ResponseDefinitionBodyMatcher {
+    #[serde(rename = "textSizeThreshold", skip_serializing_if = "Option::is_none")]
+    text_size_threshold: Option<String>,
+    #[serde(rename = "binarySizeThreshold", skip_serializing_if = "Option::is_none")]
+    binary_size_threshold: Option<String>,
+}
+
+impl ResponseDefinitionBodyMatcher {
+    pub fn text_size_threshold(&self) -> Option<&str> {
+        self.text_size_threshold.as_deref()
+    }
+
+    pub fn binary_size_threshold(&self) -> Option<&str> {
+        self.binary_size_threshold.as_deref()
+    }
+}
+
+pub struct RecordSpecBuilder {
+    record_spec: RecordSpec,
+}
+
+impl RecordSpecBuilder {
+    fn new() -> RecordSpecBuilder {
+        RecordSpecBuilder {
+            record_spec: RecordSpec::default(),
+        }
+    }
+
+    pub fn for_target<S>(mut self, target_base_url: S) -> RecordSpecBuilder
+        where S: Into<String>,
+    {
+        self.record_spec.target_base_url = Some(target_base_url.into());
+        self
+    }
+
+    /// WireMock cannot apply an inline custom matcher, so recording with it fails with
+    /// [`Error::UnsupportedInlineCustomMatcher`](../enum.Error.html#variant.UnsupportedInlineCustomMatcher).
+    pub fn only_requests_matching<P>(mut self, request_pattern: P) -> RecordSpecBuilder
+        where P: Into<RequestPattern>,
+    {
+        self.filters().filters = Some(request_pattern.into());
+        self
+    }
+
+    pub fn only_request_ids<I>(mut self, ids: I) -> RecordSpecBuilder
+        where I: IntoIterator<Item = Uuid>,
+    {
+        self.filters().ids = Some(ids.into_iter().collect());
+        self
+    }
+
+    pub fn allow_non_proxied(mut self, allow_non_proxied: bool) -> RecordSpecBuilder {
+        self.filters().allow_non_proxied = allow_non_proxied;
+        self
+    }
+
+    pub fn capture_header<S>(mut self, key: S) -> RecordSpecBuilder
+        where S: Into<String>,
+    {
+        self.record_spec.capture_headers.insert(key.into(), CaptureHeadersSpec {
+            case_insensitive: None,
+        });
+        self
+    }
+
+    pub fn capture_header_case_insensitive<S>(mut self, key: S, case_insensitive: bool) -> RecordSpecBuilder
+        where S: Into<String>,
+    {
+        self.record_spec.capture_headers.insert(key.into(), CaptureHeadersSpec {
+            case_insensitive: Some(case_insensitive),
+        });
+        self
+    }
+
+    pub fn extract_binary_bodies_over(mut self, bytes: u64) -> RecordSpecBuilder {
+        self.extract_body_criteria().binary_size_threshold = Some(bytes.to_string());
+        self
+    }
+
+    pub fn extract_text_bodies_over(mut self, bytes: u64) -> RecordSpecBuilder {
+        self.extract_body_criteria().text_size_threshold = Some(bytes.to_string());
+        self
+    }
+
+    pub fn make_stubs_persistent(mut self, persist: bool) -> RecordSpecBuilder {
+        self.record_spec.persist = Some(persist);
+        self
+    }
+
+    pub fn ignore_repeat_requests(mut self) -> RecordSpecBuilder {
+        self.record_spec.repeats_as_scenarios = Some(false);
+        self
+    }
+
+    pub fn repeats_as_scenarios(mut self, repeats_as_scenarios: bool) -> RecordSpecBuilder {
+        self.record_spec.repeats_as_scenarios = Some(repeats_as_scenarios);
+        self
+    }
+
+    pub fn transformers<I, S>(mut self, transformer_names: I) -> RecordSpecBuilder
+        where I: IntoIterator<Item = S>,
+              S: Into<String>,
+    {
+        self.record_spec.transformers.extend(transformer_names.into_iter().map(Into::into));
+        self
+    }
+
+    pub fn transformer_parameters(mut self, parameters: Parameters) -> RecordSpecBuilder {
+        self.record_spec.transformer_parameters = parameters;
+        self
+    }
+
+    pub fn match_request_body_with_equal_to_json(mut self, ignore_array_order: bool,
+                                                 ignore_extra_elements: bool) -> RecordSpecBuilder {
+        self.record_spec.request_body_pattern = Some(RequestBodyPatternFactory::EqualToJson {
+            ignore_array_order,
+            ignore_extra_elements,
+        });
+        self
+    }
+
+    pub fn match_request_body_with_equal_to_xml(mut self) -> RecordSpecBuilder {
+        self.record_spec.request_body_pattern = Some(RequestBodyPatternFactory::EqualToXml);
+        self
+    }
+
+    pub fn match_request_body_with_equal_to(mut self, case_insensitive: bool) -> RecordSpecBuilder {
+        self.record_spec.request_body_pattern = Some(RequestBodyPatternFactory::EqualTo {
+            case_insensitive,
+        });
+        self
+    }
+
+    pub fn choose_body_match_type_automatically(mut self, ignore_array_order: bool,
+                                                ignore_extra_elements: bool,
+                                                case_insensitive: bool) -> RecordSpecBuilder {
+        self.record_spec.request_body_pattern = Some(RequestBodyPatternFactory::Auto {
+            ignore_array_order,
+            ignore_extra_elements,
+            case_insensitive,
+        });
+        self
+    }
+
+    pub fn build(self) -> RecordSpec {
+        self.record_spec
+    }
+
+    fn filters(&mut self) -> &mut ProxiedServeEventFilters {
+        self.record_spec.filters.get_or_insert_with(ProxiedServeEventFilters::default)
+    }
+
+    fn extract_body_criteria(&mut self) -> &mut ResponseDefinitionBodyMatcher {
+        self.record_spec.extract_body_criteria.get_or_insert_with(ResponseDefinitionBodyMatcher::default)
+    }
+}
@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone, Hash)]
+pub enum RecordingStatus {
+    NeverStarted,
+    Recording,
+    Stopped,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+pub struct RecordingStatusResult {
+    status: RecordingStatus,
+}
+
+impl RecordingStatusResult {
+    pub fn status(&self) -> RecordingStatus {
+        self.status
+    }
+}
+
+impl From<RecordingStatusResult> for RecordingStatus {
+    fn from(result: RecordingStatusResult) -> RecordingStatus {
+        result.status
+    }
+}
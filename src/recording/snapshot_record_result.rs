@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+use crate::stubbing::StubMapping;
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct SnapshotRecordResult {
+    mappings: Vec<StubMapping>,
+}
+
+impl SnapshotRecordResult {
+    pub fn stub_mappings(&self) -> &[StubMapping] {
+        &self.mappings
+    }
+}
+
+impl From<SnapshotRecordResult> for Vec<StubMapping> {
+    fn from(result: SnapshotRecordResult) -> Vec<StubMapping> {
+        result.mappings
+    }
+}
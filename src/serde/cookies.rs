@@ -0,0 +1,15 @@
+use indexmap::IndexMap;
+use serde::{Deserialize, Deserializer};
+
+use crate::http::Cookie;
+
+/// Sets the key of each cookie from its map key.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<IndexMap<String, Cookie>, D::Error>
+    where D: Deserializer<'de>
+{
+    let mut cookies = IndexMap::<String, Cookie>::deserialize(deserializer)?;
+    for (key, cookie) in cookies.iter_mut() {
+        cookie.key = key.clone();
+    }
+    Ok(cookies)
+}
@@ -151,8 +151,7 @@ impl<'de> Visitor<'de> for HeaderMapVisitor {
                     headers.append(header_name, header_value);
                 },
                 StrStringOrSeq::OwnedStrSeq(values) => {
-                    let values_iter = values.iter()
-                        .map(|value| *value);
+                    let values_iter = values.iter().copied();
                     add_header_values(&mut headers, &header_name, values_iter)?;
                 }
                 StrStringOrSeq::String(value) => {
@@ -0,0 +1,41 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de;
+
+/// WireMock logs bodies both as `bodyAsBase64` and as (lossy) text `body`.
+#[derive(Serialize)]
+struct LoggedBodyRef<'a> {
+    body: Cow<'a, str>,
+    #[serde(rename = "bodyAsBase64")]
+    body_as_base64: String,
+}
+
+#[derive(Deserialize)]
+struct LoggedBody {
+    body: Option<String>,
+    #[serde(rename = "bodyAsBase64")]
+    body_as_base64: Option<String>,
+}
+
+pub fn serialize<S>(value: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+    where S: Serializer
+{
+    LoggedBodyRef {
+        body: String::from_utf8_lossy(value),
+        body_as_base64: base64::encode(value),
+    }.serialize(serializer)
+}
+
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+    where D: Deserializer<'de>
+{
+    let logged_body = LoggedBody::deserialize(deserializer)?;
+    match (logged_body.body_as_base64, logged_body.body) {
+        (Some(body_as_base64), _) => base64::decode(&body_as_base64)
+            .map_err(|_decode_error| de::Error::invalid_value(de::Unexpected::Str(&body_as_base64),
+                &"a string representation of base64 encoded data")),
+        (None, Some(body)) => Ok(body.into_bytes()),
+        (None, None) => Ok(Vec::new()),
+    }
+}
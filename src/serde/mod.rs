@@ -1,7 +1,16 @@
+use crate::http::RequestMethod;
+
 pub mod base64;
+pub mod cookies;
 pub mod header_map;
+pub mod logged_body;
+pub mod optional_rfc3339;
 pub mod u32_negative_to_option;
 
-pub fn default_true() -> bool {
-    true
+pub fn default_status() -> u16 {
+    200
+}
+
+pub fn default_method() -> RequestMethod {
+    RequestMethod::ANY
 }
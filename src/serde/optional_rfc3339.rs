@@ -0,0 +1,44 @@
+use std::fmt;
+use std::time::SystemTime;
+
+use serde::{Deserializer, Serializer};
+use serde::de::{self, Visitor};
+
+pub fn serialize<S>(value: &Option<SystemTime>, serializer: S) -> Result<S::Ok, S::Error>
+    where S: Serializer
+{
+    match value {
+        Some(value) => serializer.collect_str(&humantime::format_rfc3339_millis(*value)),
+        None => serializer.serialize_none(),
+    }
+}
+
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<SystemTime>, D::Error>
+    where D: Deserializer<'de>
+{
+    deserializer.deserialize_option(OptionalRfc3339Visitor)
+}
+
+struct OptionalRfc3339Visitor;
+
+impl<'de> Visitor<'de> for OptionalRfc3339Visitor {
+    type Value = Option<SystemTime>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        formatter.write_str("an RFC 3339 timestamp")
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E> where E: de::Error {
+        Ok(None)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error> where D: Deserializer<'de> {
+        deserializer.deserialize_str(self)
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E> where E: de::Error {
+        humantime::parse_rfc3339_weak(value)
+            .map(Some)
+            .map_err(de::Error::custom)
+    }
+}
@@ -0,0 +1,188 @@
+use std::ffi::OsStr;
+use std::fs;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use http::header::CONTENT_TYPE;
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::error::{Error, Result};
+use crate::http::Body;
+use crate::stubbing::StubMapping;
+
+#[derive(Deserialize)]
+struct MappingsFile {
+    mappings: Vec<StubMapping>,
+}
+
+/// Loads the stub mappings of all `.json` files in the directory and its subdirectories,
+/// like WireMock does for its `mappings` directory.
+///
+/// A file contains either a single stub mapping or multiple ones as `{"mappings": [...]}`.
+/// Files are loaded in order of their paths and mappings without an id get a random one.
+pub fn load_mappings_dir<P: AsRef<Path>>(path: P) -> Result<Vec<StubMapping>> {
+    let mut file_paths = Vec::new();
+    collect_json_files(path.as_ref(), &mut file_paths)?;
+    file_paths.sort();
+
+    let mut stub_mappings = Vec::new();
+    for file_path in file_paths {
+        stub_mappings.extend(load_mappings_file(&file_path)?);
+    }
+    Ok(stub_mappings)
+}
+
+/// Writes the stub mappings as one `.json` file per stub into the directory, like WireMock does for its `mappings` directory.
+///
+/// Files are named from the stub mapping name (or the id if there is none).
+/// Returns the paths of the written mapping files.
+pub fn write_mappings_dir<P: AsRef<Path>>(path: P, stub_mappings: Vec<StubMapping>, options: ExportOptions)
+    -> Result<Vec<PathBuf>>
+{
+    let path = path.as_ref();
+    let files_path = path.parent().unwrap_or(path).join("__files");
+    fs::create_dir_all(path).map_err(|error| io_error(path, error))?;
+
+    let mut file_names = HashSet::new();
+    let mut file_paths = Vec::with_capacity(stub_mappings.len());
+    for mut stub_mapping in stub_mappings {
+        let file_stem = unique_file_stem(&stub_mapping, &mut file_names);
+        if let Some(max_size) = options.extract_bodies_larger_than {
+            extract_body(&mut stub_mapping, &files_path, &file_stem, max_size)?;
+        }
+
+        let file_path = path.join(format!("{}.json", file_stem));
+        let content = serde_json::to_vec_pretty(&stub_mapping)?;
+        fs::write(&file_path, content).map_err(|error| io_error(&file_path, error))?;
+        file_paths.push(file_path);
+    }
+    Ok(file_paths)
+}
+
+/// Options for writing stub mappings with [`write_mappings_dir`](fn.write_mappings_dir.html).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ExportOptions {
+    extract_bodies_larger_than: Option<usize>,
+}
+
+impl ExportOptions {
+    pub const DEFAULTS: ExportOptions = ExportOptions {
+        extract_bodies_larger_than: None,
+    };
+
+    /// Moves response bodies larger than the size in bytes into the sibling `__files` directory
+    /// and refers to them with `bodyFileName` instead.
+    pub fn with_extracted_bodies_larger_than(mut self, size: usize) -> ExportOptions {
+        self.extract_bodies_larger_than = Some(size);
+        self
+    }
+
+    pub fn extract_bodies_larger_than(&self) -> Option<usize> {
+        self.extract_bodies_larger_than
+    }
+}
+
+impl Default for ExportOptions {
+    fn default() -> ExportOptions {
+        ExportOptions::DEFAULTS
+    }
+}
+
+fn unique_file_stem(stub_mapping: &StubMapping, file_names: &mut HashSet<String>) -> String {
+    let id = stub_mapping.id().to_string();
+    let file_stem = match stub_mapping.name().map(sanitize_file_name) {
+        Some(name) if !name.is_empty() && !file_names.contains(&name) => name,
+        Some(name) if !name.is_empty() => format!("{}-{}", name, id),
+        _ => id,
+    };
+    file_names.insert(file_stem.clone());
+    file_stem
+}
+
+/// Lowercases the name and replaces every run of other characters than letters and digits with a `-`.
+fn sanitize_file_name(name: &str) -> String {
+    name.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<&str>>()
+        .join("-")
+}
+
+fn extract_body(stub_mapping: &mut StubMapping, files_path: &Path, file_stem: &str, max_size: usize) -> Result<()> {
+    let content = match &stub_mapping.response.body {
+        Some(Body::String(body)) => body.clone().into_bytes(),
+        Some(Body::Base64(body)) => body.clone(),
+        Some(Body::Json(body)) => serde_json::to_vec_pretty(body)?,
+        _ => return Ok(()),
+    };
+    if content.len() <= max_size {
+        return Ok(());
+    }
+
+    let content_type = stub_mapping.response.headers.get(CONTENT_TYPE)
+        .and_then(|content_type| content_type.to_str().ok());
+    let extension = match (&stub_mapping.response.body, content_type) {
+        (Some(Body::Json(_)), _) => "json",
+        (Some(Body::Base64(_)), _) => "bin",
+        (_, Some(content_type)) if content_type.contains("json") => "json",
+        (_, Some(content_type)) if content_type.contains("xml") => "xml",
+        (_, Some(content_type)) if content_type.contains("html") => "html",
+        _ => "txt",
+    };
+    let file_name = format!("{}-body.{}", file_stem, extension);
+
+    fs::create_dir_all(files_path).map_err(|error| io_error(files_path, error))?;
+    let file_path = files_path.join(&file_name);
+    fs::write(&file_path, content).map_err(|error| io_error(&file_path, error))?;
+    stub_mapping.response.body = Some(Body::FileName(file_name));
+    Ok(())
+}
+
+fn collect_json_files(dir: &Path, file_paths: &mut Vec<PathBuf>) -> Result<()> {
+    let entries = fs::read_dir(dir).map_err(|error| io_error(dir, error))?;
+    for entry in entries {
+        let path = entry.map_err(|error| io_error(dir, error))?.path();
+        if path.is_dir() {
+            collect_json_files(&path, file_paths)?;
+        } else if path.extension() == Some(OsStr::new("json")) {
+            file_paths.push(path);
+        }
+    }
+    Ok(())
+}
+
+fn load_mappings_file(path: &Path) -> Result<Vec<StubMapping>> {
+    let content = fs::read(path).map_err(|error| io_error(path, error))?;
+    let value: Value = serde_json::from_slice(&content)
+        .map_err(|error| invalid_mapping_file(path, String::from("."), error))?;
+
+    let mut stub_mappings = if value.get("mappings").is_some() {
+        parse::<MappingsFile>(path, value)?.mappings
+    } else {
+        vec![parse::<StubMapping>(path, value)?]
+    };
+    // files of older WireMock versions may only contain the legacy `uuid`
+    for stub_mapping in &mut stub_mappings {
+        if let Some(uuid) = stub_mapping.uuid {
+            stub_mapping.id = uuid;
+        }
+    }
+    Ok(stub_mappings)
+}
+
+fn parse<T: DeserializeOwned>(path: &Path, value: Value) -> Result<T> {
+    serde_path_to_error::deserialize(value).map_err(|error| {
+        let json_path = error.path().to_string();
+        invalid_mapping_file(path, json_path, error.into_inner())
+    })
+}
+
+fn io_error(path: &Path, error: std::io::Error) -> Error {
+    Error::Io { path: path.to_path_buf(), error }
+}
+
+fn invalid_mapping_file(path: &Path, json_path: String, error: serde_json::Error) -> Error {
+    Error::InvalidMappingFile { path: path.to_path_buf(), json_path, error }
+}
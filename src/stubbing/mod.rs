@@ -1,3 +1,8 @@
+pub use crate::stubbing::mapping_files::{
+    ExportOptions as MappingsExportOptions,
+    load_mappings_dir,
+    write_mappings_dir,
+};
 pub use crate::stubbing::scenario::Scenario;
 pub use crate::stubbing::serve_event::ServeEvent;
 pub use crate::stubbing::stub_import::{
@@ -12,3 +17,4 @@ mod stub_mapping;
 mod scenario;
 mod serve_event;
 mod stub_import;
+mod mapping_files;
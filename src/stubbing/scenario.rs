@@ -1,15 +1,16 @@
 use serde::{Serialize, Deserialize};
 use uuid::Uuid;
-use std::collections::HashSet;
+use indexmap::IndexSet;
 use crate::stubbing::StubMapping;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct Scenario {
     id: Uuid,
     name: String,
     state: String,
     #[serde(rename = "possibleStates")]
-    possible_states: HashSet<String>,
+    possible_states: IndexSet<String>,
+    #[serde(default)]
     mappings: Vec<StubMapping>,
 }
 
@@ -21,7 +22,7 @@ impl Scenario {
             id: Uuid::new_v4(),
             name: name.into(),
             state: Scenario::STARTED.to_string(),
-            possible_states: HashSet::new(),
+            possible_states: IndexSet::new(),
             mappings: Vec::new(),
         }
     }
@@ -38,7 +39,7 @@ impl Scenario {
         &self.state
     }
 
-    pub fn possible_states(&self) -> &HashSet<String> {
+    pub fn possible_states(&self) -> &IndexSet<String> {
         &self.possible_states
     }
 
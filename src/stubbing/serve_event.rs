@@ -8,7 +8,7 @@ use crate::extension::Parameters;
 use crate::common::Timing;
 use crate::verification::LoggedRequest;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct ServeEvent {
     id: Uuid,
     request: LoggedRequest,
@@ -6,9 +6,10 @@ pub fn stub_import() -> StubImportBuilder {
     StubImportBuilder::new()
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct StubImport {
     mappings: Vec<StubMapping>,
+    #[serde(rename = "importOptions", default)]
     import_options: Options,
 }
 
@@ -28,15 +29,17 @@ impl From<StubImportBuilder> for StubImport {
     }
 }
 
-impl Into<Vec<StubMapping>> for StubImport {
-    fn into(self) -> Vec<StubMapping> {
-        self.mappings
+impl From<StubImport> for Vec<StubMapping> {
+    fn from(stub_import: StubImport) -> Vec<StubMapping> {
+        stub_import.mappings
     }
 }
 
-#[derive(Debug, Serialize, Deserialize, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq, Eq, Hash)]
 pub struct Options {
+    #[serde(rename = "duplicatePolicy", default)]
     duplicate_policy: DuplicatePolicy,
+    #[serde(rename = "deleteAllNotInImport", default)]
     delete_all_not_in_import: bool,
 }
 
@@ -45,11 +48,26 @@ impl Options {
         duplicate_policy: DuplicatePolicy::OVERWRITE,
         delete_all_not_in_import: false,
     };
+
+    pub fn duplicate_policy(&self) -> DuplicatePolicy {
+        self.duplicate_policy
+    }
+
+    pub fn delete_all_not_in_import(&self) -> bool {
+        self.delete_all_not_in_import
+    }
+}
+
+impl Default for Options {
+    fn default() -> Options {
+        Options::DEFAULTS
+    }
 }
 
 #[allow(non_camel_case_types)]
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone, Default, Hash)]
 pub enum DuplicatePolicy {
+    #[default]
     OVERWRITE,
     IGNORE,
 }
@@ -8,10 +8,14 @@ use crate::client::builder::{MappingBuilder, ScenarioMappingBuilder};
 use crate::common::Metadata;
 use crate::extension::Parameters;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct StubMapping {
-    /// This stub mapping's unique identifier.
+    /// This stub mapping's unique identifier (generated if missing, e.g. in mapping files).
+    #[serde(default = "Uuid::new_v4")]
     pub(crate) id: Uuid,
+    /// The same identifier under its legacy name, as sent by older WireMock versions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) uuid: Option<Uuid>,
     /// The stub mapping's name.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) name: Option<String>,
@@ -47,6 +51,9 @@ pub struct StubMapping {
 impl StubMapping {
     pub fn set_id(&mut self, id: Uuid) {
         self.id = id;
+        if self.uuid.is_some() {
+            self.uuid = Some(id);
+        }
     }
 
     pub fn id(&self) -> &Uuid {
@@ -58,7 +65,7 @@ impl StubMapping {
     }
 
     pub fn name(&self) -> Option<&str> {
-        self.name.as_ref().map(|name| name.as_str())
+        self.name.as_deref()
     }
 
     pub fn set_persistent(&mut self, persistent: bool) {
@@ -94,7 +101,7 @@ impl StubMapping {
     }
 
     pub fn scenario_name(&self) -> Option<&str> {
-        self.scenario_name.as_ref().map(|scenario_name| scenario_name.as_str())
+        self.scenario_name.as_deref()
     }
 
     pub fn set_scenario_name<S>(&mut self, scenario_name: S) where S: Into<String> {
@@ -102,7 +109,7 @@ impl StubMapping {
     }
 
     pub fn required_scenario_state(&self) -> Option<&str> {
-        self.required_scenario_state.as_ref().map(|state| state.as_str())
+        self.required_scenario_state.as_deref()
     }
 
     pub fn set_required_scenario_state<S>(&mut self, required_scenario_state: S) where S: Into<String> {
@@ -110,7 +117,7 @@ impl StubMapping {
     }
 
     pub fn new_scenario_state(&self) -> Option<&str> {
-        self.new_scenario_state.as_ref().map(|state| state.as_str())
+        self.new_scenario_state.as_deref()
     }
 
     pub fn set_new_scenario_state<S>(&mut self, new_scenario_state: S) where S: Into<String> {
@@ -165,3 +172,27 @@ impl From<ScenarioMappingBuilder> for StubMapping {
         builder.build()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{equal_to, get, ok_json, url_equal_to};
+    use crate::http::RequestMethod;
+    use crate::verification::LoggedRequestBuilder;
+
+    #[test]
+    fn compare_stub_mappings() {
+        let stub_mapping = get(url_equal_to("/things"))
+            .with_header("Accept", equal_to("application/json"))
+            .will_return(ok_json(r#"{"things": []}"#))
+            .build();
+        let copy = stub_mapping.clone();
+        assert_eq!(stub_mapping, copy);
+
+        let mut other = stub_mapping.clone();
+        other.set_priority(1);
+        assert_ne!(stub_mapping, other);
+
+        let request = LoggedRequestBuilder::new(RequestMethod::GET, "/things").build();
+        assert_eq!(request.clone(), request);
+    }
+}
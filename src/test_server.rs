@@ -0,0 +1,567 @@
+/*!
+An in-process fake of the WireMock admin API for hermetic tests.
+
+The [`TestServer`](struct.TestServer.html) keeps stub mappings, scenario states, global settings
+and the recording status in memory, so a [`WireMock`](../struct.WireMock.html) client can be exercised
+without a running WireMock JVM.
+
+It is deliberately not a WireMock replacement:
+* Stubs and body files are only stored, requests to anything but `/__admin` are answered with `404 Not Found`.
+* These requests are logged as unmatched serve events in the request journal,
+  which is paged with `limit` and `since` and searched with [`evaluate`](../matching/fn.evaluate.html).
+  Near misses are never found.
+* Operations that need WireMock's metadata matching are answered with `501 Not Implemented`.
+
+```rust,no_run
+use wiremock_client::*;
+use wiremock_client::test_server::TestServer;
+
+let server = TestServer::start().unwrap();
+let wire_mock = server.wire_mock();
+
+wire_mock.stub_for(get(url_equal_to("/some/thing"))
+    .will_return(ok())).unwrap();
+assert_eq!(server.stub_mappings().len(), 1);
+```
+*/
+
+use std::collections::HashMap;
+use std::io;
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::UNIX_EPOCH;
+
+use indexmap::{IndexMap, IndexSet};
+use percent_encoding::percent_decode_str;
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use tiny_http::{Header, Method, Request, Response, Server};
+use uuid::Uuid;
+
+use crate::client::{WireMock, WireMockBuilder};
+#[cfg(feature = "async")]
+use crate::client::AsyncWireMock;
+use crate::global::{GlobalSettings, GlobalSettingsBuilder};
+use crate::http::RequestMethod;
+use crate::matching::{evaluate, RequestPattern};
+use crate::recording::RecordingStatus;
+use crate::stubbing::{Scenario, ServeEvent, StubImport, StubImportDuplicatePolicy, StubMapping};
+use crate::verification::{LoggedRequest, LoggedRequestBuilder};
+
+const ADMIN_PATH_PREFIX: &str = "/__admin";
+const NO_STUB_MAPPINGS: &str = "No response could be served as there are no stub mappings in this test server";
+
+/// Fake WireMock admin API running on a background thread.
+///
+/// Binds to a random free port on localhost and stops when dropped.
+pub struct TestServer {
+    port: u16,
+    server: Arc<Server>,
+    state: Arc<Mutex<State>>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl TestServer {
+    pub fn start() -> io::Result<TestServer> {
+        let server = Server::http("127.0.0.1:0")
+            .map(Arc::new)
+            .map_err(io::Error::other)?;
+        let port = server.server_addr().to_ip()
+            .map(|address| address.port())
+            .ok_or_else(|| io::Error::other("test server is not listening on an IP address"))?;
+        let state = Arc::new(Mutex::new(State::new()));
+
+        let handle = {
+            let server = server.clone();
+            let state = state.clone();
+            thread::Builder::new()
+                .name(format!("wiremock-test-server-{}", port))
+                .spawn(move || serve(&server, &state))?
+        };
+
+        Ok(TestServer {
+            port,
+            server,
+            state,
+            handle: Some(handle),
+        })
+    }
+
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
+    pub fn base_url(&self) -> String {
+        format!("http://localhost:{}", self.port)
+    }
+
+    /// Creates a client that talks to this server.
+    pub fn wire_mock(&self) -> WireMock {
+        WireMockBuilder::new()
+            .port(self.port)
+            .build()
+    }
+
+    /// Creates an async client that talks to this server.
+    #[cfg(feature = "async")]
+    pub fn async_wire_mock(&self) -> AsyncWireMock {
+        WireMockBuilder::new()
+            .port(self.port)
+            .build_async()
+    }
+
+    /// The currently stored stub mappings, newest first (like WireMock lists them).
+    pub fn stub_mappings(&self) -> Vec<StubMapping> {
+        let state = self.state.lock().unwrap();
+        state.mappings.values().rev()
+            .cloned()
+            .collect()
+    }
+}
+
+impl Drop for TestServer {
+    fn drop(&mut self) {
+        self.server.unblock();
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+struct State {
+    mappings: IndexMap<Uuid, StubMapping>,
+    /// Assigned when the first stub mapping of a scenario is stored.
+    scenario_ids: HashMap<String, Uuid>,
+    scenario_states: HashMap<String, String>,
+    settings: GlobalSettings,
+    recording_status: RecordingStatus,
+    files: IndexMap<String, Vec<u8>>,
+    /// Oldest first.
+    journal: Vec<ServeEvent>,
+}
+
+impl State {
+    fn new() -> State {
+        State {
+            mappings: IndexMap::new(),
+            scenario_ids: HashMap::new(),
+            scenario_states: HashMap::new(),
+            settings: GlobalSettingsBuilder::new().build(),
+            recording_status: RecordingStatus::NeverStarted,
+            files: IndexMap::new(),
+            journal: Vec::new(),
+        }
+    }
+}
+
+struct AdminResponse {
+    status: u16,
+    body: Option<AdminResponseBody>,
+}
+
+enum AdminResponseBody {
+    Json(Value),
+    Bytes(Vec<u8>),
+}
+
+impl AdminResponse {
+    fn ok() -> AdminResponse {
+        AdminResponse::status(200)
+    }
+
+    fn status(status: u16) -> AdminResponse {
+        AdminResponse {
+            status,
+            body: None,
+        }
+    }
+
+    fn json(status: u16, body: Value) -> AdminResponse {
+        AdminResponse {
+            status,
+            body: Some(AdminResponseBody::Json(body)),
+        }
+    }
+
+    fn bytes(body: Vec<u8>) -> AdminResponse {
+        AdminResponse {
+            status: 200,
+            body: Some(AdminResponseBody::Bytes(body)),
+        }
+    }
+
+    fn error<S: Into<String>>(status: u16, title: S) -> AdminResponse {
+        AdminResponse::json(status, json!({ "errors": [{ "title": title.into() }] }))
+    }
+}
+
+fn serve(server: &Server, state: &Mutex<State>) {
+    for request in server.incoming_requests() {
+        // A failed response only affects the client that sent the request.
+        let _ = respond(request, state);
+    }
+}
+
+fn respond(mut request: Request, state: &Mutex<State>) -> io::Result<()> {
+    let mut body = Vec::new();
+    request.as_reader().read_to_end(&mut body)?;
+
+    let url = request.url().to_string();
+    let (path, query) = url.split_once('?').unwrap_or((&url, ""));
+
+    let admin_response = {
+        let mut state = state.lock().unwrap();
+        match path.strip_prefix(ADMIN_PATH_PREFIX) {
+            Some(admin_path) => handle(&mut state, request.method(), admin_path, query, &body),
+            None => log_unmatched_request(&mut state, &request, &body),
+        }
+    };
+
+    let response = match admin_response.body {
+        Some(AdminResponseBody::Json(body)) => Response::from_string(body.to_string())
+            .with_header(Header::from_bytes(&b"Content-Type"[..], &b"application/json"[..]).unwrap()),
+        Some(AdminResponseBody::Bytes(body)) => Response::from_data(body),
+        None => Response::from_string(String::new()),
+    };
+    request.respond(response.with_status_code(admin_response.status))
+}
+
+fn handle(state: &mut State, method: &Method, path: &str, query: &str, body: &[u8]) -> AdminResponse {
+    let segments = path.split('/')
+        .filter(|segment| !segment.is_empty())
+        .collect::<Vec<&str>>();
+
+    let result = match (method, segments.as_slice()) {
+        (Method::Get, []) | (Method::Get, ["mappings"]) => Ok(list_stub_mappings(state)),
+        (Method::Post, ["mappings"]) => add_stub_mapping(state, body),
+        (Method::Delete, ["mappings"]) | (Method::Post, ["mappings", "reset"]) => {
+            state.mappings.clear();
+            state.scenario_ids.clear();
+            Ok(AdminResponse::ok())
+        },
+        (Method::Post, ["mappings", "save"]) => Ok(AdminResponse::ok()),
+        (Method::Post, ["mappings", "import"]) => import_stub_mappings(state, body),
+        (Method::Post, ["mappings", "find-by-metadata"])
+        | (Method::Post, ["mappings", "remove-by-metadata"])
+        | (Method::Post, ["requests", "remove-by-metadata"]) => {
+            Ok(AdminResponse::error(501, "Metadata matching is not supported by the test server"))
+        },
+        (Method::Get, ["mappings", id]) => get_stub_mapping(state, id),
+        (Method::Put, ["mappings", id]) => edit_stub_mapping(state, id, body),
+        (Method::Delete, ["mappings", id]) => remove_stub_mapping(state, id),
+        (Method::Post, ["reset"]) => {
+            state.mappings.clear();
+            state.scenario_ids.clear();
+            state.scenario_states.clear();
+            state.journal.clear();
+            Ok(AdminResponse::ok())
+        },
+        (Method::Delete, ["requests"]) | (Method::Post, ["requests", "reset"]) => {
+            state.journal.clear();
+            Ok(AdminResponse::ok())
+        },
+        (Method::Get, ["requests"]) => list_serve_events(state, query),
+        (Method::Post, ["requests", "count"]) => find_requests(state, body).map(|requests| {
+            AdminResponse::json(200, json!({
+                "count": requests.len(),
+                "requestJournalDisabled": false,
+            }))
+        }),
+        (Method::Post, ["requests", "find"]) => find_requests(state, body).map(|requests| {
+            AdminResponse::json(200, json!({
+                "requests": requests,
+                "requestJournalDisabled": false,
+            }))
+        }),
+        (Method::Get, ["requests", "unmatched"]) => Ok(AdminResponse::json(200, json!({
+            "requests": state.journal.iter()
+                .filter(|serve_event| !serve_event.was_matched())
+                .map(ServeEvent::request)
+                .collect::<Vec<&LoggedRequest>>(),
+            "requestJournalDisabled": false,
+        }))),
+        (Method::Post, ["requests", "remove"]) => remove_serve_events(state, body),
+        (Method::Get, ["requests", "unmatched", "near-misses"]) | (Method::Post, ["near-misses", _]) => {
+            Ok(AdminResponse::json(200, json!({ "nearMisses": [] })))
+        },
+        (Method::Get, ["requests", id]) => get_serve_event(state, id),
+        (Method::Delete, ["requests", id]) => {
+            state.journal.retain(|serve_event| serve_event.id().to_string() != *id);
+            Ok(AdminResponse::ok())
+        },
+        (Method::Get, ["scenarios"]) => Ok(AdminResponse::json(200, json!({ "scenarios": scenarios(state) }))),
+        (Method::Post, ["scenarios", "reset"]) => {
+            state.scenario_states.clear();
+            Ok(AdminResponse::ok())
+        },
+        (Method::Put, ["scenarios", name, "state"]) => set_scenario_state(state, name, body),
+        (Method::Get, ["settings"]) => Ok(AdminResponse::json(200, json!({ "settings": state.settings }))),
+        (Method::Post, ["settings"]) | (Method::Put, ["settings"]) => {
+            parse::<GlobalSettings>(body).map(|settings| {
+                state.settings = settings;
+                AdminResponse::ok()
+            })
+        },
+        (Method::Post, ["shutdown"]) => Ok(AdminResponse::ok()),
+        (Method::Post, ["recordings", "start"]) => {
+            state.recording_status = RecordingStatus::Recording;
+            Ok(AdminResponse::ok())
+        },
+        (Method::Post, ["recordings", "stop"]) => {
+            state.recording_status = RecordingStatus::Stopped;
+            Ok(AdminResponse::json(200, json!({ "mappings": [] })))
+        },
+        (Method::Get, ["recordings", "status"]) => {
+            Ok(AdminResponse::json(200, json!({ "status": state.recording_status })))
+        },
+        (Method::Post, ["recordings", "snapshot"]) => Ok(AdminResponse::json(200, json!({ "mappings": [] }))),
+        (Method::Get, ["files"]) => Ok(AdminResponse::json(200, json!(state.files.keys().collect::<Vec<&String>>()))),
+        (Method::Get, ["files", name @ ..]) => state.files.get(&file_name(name))
+            .map(|content| AdminResponse::bytes(content.clone()))
+            .ok_or_else(|| AdminResponse::error(404, format!("File {} not found", file_name(name)))),
+        (Method::Put, ["files", name @ ..]) => {
+            state.files.insert(file_name(name), body.to_vec());
+            Ok(AdminResponse::ok())
+        },
+        (Method::Delete, ["files", name @ ..]) => {
+            state.files.shift_remove(&file_name(name))
+                .map(|_| AdminResponse::ok())
+                .ok_or_else(|| AdminResponse::error(404, format!("File {} not found", file_name(name))))
+        },
+        _ => Ok(AdminResponse::error(404, format!("No admin route for {} {}", method, path))),
+    };
+
+    result.unwrap_or_else(|error_response| error_response)
+}
+
+fn parse<T: DeserializeOwned>(body: &[u8]) -> Result<T, AdminResponse> {
+    serde_json::from_slice(body)
+        .map_err(|error| AdminResponse::error(422, error.to_string()))
+}
+
+fn parse_id(id: &str) -> Result<Uuid, AdminResponse> {
+    Uuid::parse_str(id)
+        .map_err(|_| AdminResponse::error(404, format!("Stub mapping {} not found", id)))
+}
+
+fn file_name(segments: &[&str]) -> String {
+    segments.iter()
+        .map(|segment| percent_decode_str(segment).decode_utf8_lossy().into_owned())
+        .collect::<Vec<String>>()
+        .join("/")
+}
+
+fn log_unmatched_request(state: &mut State, request: &Request, body: &[u8]) -> AdminResponse {
+    let logged_request = request.headers().iter()
+        .fold(LoggedRequestBuilder::new(RequestMethod::from(request.method().as_str()), request.url()),
+            |builder, header| builder.with_header(header.field.as_str(), header.value.as_str()))
+        .with_body(body)
+        .build();
+    let serve_event = json!({
+        "id": Uuid::new_v4(),
+        "request": logged_request,
+        "responseDefinition": { "status": 404 },
+        "response": { "status": 404 },
+        "wasMatched": false,
+        "timing": { "addedDelay": 0, "processTime": 0, "responseSendTime": 0 },
+        "stubMapping": { "request": { "method": "ANY" }, "response": { "status": 404 } },
+    });
+    state.journal.push(serde_json::from_value(serve_event).expect("unmatched serve event"));
+
+    AdminResponse::error(404, NO_STUB_MAPPINGS)
+}
+
+/// Like WireMock, only `limit` and `since` select the serve events, newest first.
+fn list_serve_events(state: &State, query: &str) -> Result<AdminResponse, AdminResponse> {
+    let limit = query_param(query, "limit")
+        .map(|limit| limit.parse::<usize>()
+            .map_err(|_| AdminResponse::error(400, format!("Invalid limit {}", limit))))
+        .transpose()?;
+    let since = query_param(query, "since")
+        .map(|since| humantime::parse_rfc3339_weak(&since)
+            .map_err(|_| AdminResponse::error(400, format!("Invalid since {}", since))))
+        .transpose()?
+        .and_then(|since| since.duration_since(UNIX_EPOCH).ok())
+        .map(|since| since.as_millis() as i64);
+
+    let serve_events = state.journal.iter().rev()
+        .filter(|serve_event| since.map_or(true, |since| serve_event.request().logged_date() > since))
+        .take(limit.unwrap_or(usize::MAX))
+        .collect::<Vec<&ServeEvent>>();
+    Ok(AdminResponse::json(200, json!({
+        "requests": serve_events,
+        "meta": { "total": state.journal.len() },
+        "requestJournalDisabled": false,
+    })))
+}
+
+fn query_param(query: &str, name: &str) -> Option<String> {
+    query.split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(key, _)| *key == name)
+        .map(|(_, value)| percent_decode_str(value).decode_utf8_lossy().into_owned())
+}
+
+fn find_requests<'a>(state: &'a State, body: &[u8]) -> Result<Vec<&'a LoggedRequest>, AdminResponse> {
+    let request_pattern = parse::<RequestPattern>(body)?;
+    Ok(state.journal.iter()
+        .map(ServeEvent::request)
+        .filter(|request| evaluate(&request_pattern, request).is_exact_match())
+        .collect())
+}
+
+fn remove_serve_events(state: &mut State, body: &[u8]) -> Result<AdminResponse, AdminResponse> {
+    let request_pattern = parse::<RequestPattern>(body)?;
+    let (removed, kept) = state.journal.drain(..)
+        .partition::<Vec<ServeEvent>, _>(|serve_event| {
+            evaluate(&request_pattern, serve_event.request()).is_exact_match()
+        });
+    state.journal = kept;
+    Ok(AdminResponse::json(200, json!({
+        "serveEvents": removed,
+        "requestJournalDisabled": false,
+    })))
+}
+
+fn get_serve_event(state: &State, id: &str) -> Result<AdminResponse, AdminResponse> {
+    state.journal.iter()
+        .find(|serve_event| serve_event.id().to_string() == id)
+        .map(|serve_event| AdminResponse::json(200, json!(serve_event)))
+        .ok_or_else(|| AdminResponse::error(404, "Serve event not found"))
+}
+
+fn list_stub_mappings(state: &State) -> AdminResponse {
+    let mappings = state.mappings.values().rev().collect::<Vec<&StubMapping>>();
+    AdminResponse::json(200, json!({
+        "mappings": mappings,
+        "meta": { "total": mappings.len() },
+    }))
+}
+
+fn add_stub_mapping(state: &mut State, body: &[u8]) -> Result<AdminResponse, AdminResponse> {
+    let stub_mapping = parse::<StubMapping>(body)?;
+    let json = json!(stub_mapping);
+    insert_stub_mapping(state, stub_mapping);
+    Ok(AdminResponse::json(201, json))
+}
+
+fn insert_stub_mapping(state: &mut State, stub_mapping: StubMapping) {
+    if let Some(scenario_name) = stub_mapping.scenario_name() {
+        state.scenario_ids.entry(scenario_name.to_string()).or_insert_with(Uuid::new_v4);
+    }
+    state.mappings.insert(*stub_mapping.id(), stub_mapping);
+}
+
+fn import_stub_mappings(state: &mut State, body: &[u8]) -> Result<AdminResponse, AdminResponse> {
+    let stub_import = parse::<StubImport>(body)?;
+    let import_options = stub_import.import_options();
+    let stub_mappings: Vec<StubMapping> = stub_import.into();
+
+    if import_options.delete_all_not_in_import() {
+        let imported_ids = stub_mappings.iter()
+            .map(|stub_mapping| *stub_mapping.id())
+            .collect::<IndexSet<Uuid>>();
+        state.mappings.retain(|id, _| imported_ids.contains(id));
+    }
+
+    for stub_mapping in stub_mappings {
+        let exists = state.mappings.contains_key(stub_mapping.id());
+        if !exists || import_options.duplicate_policy() == StubImportDuplicatePolicy::OVERWRITE {
+            insert_stub_mapping(state, stub_mapping);
+        }
+    }
+
+    Ok(AdminResponse::ok())
+}
+
+fn get_stub_mapping(state: &State, id: &str) -> Result<AdminResponse, AdminResponse> {
+    let id = parse_id(id)?;
+    state.mappings.get(&id)
+        .map(|stub_mapping| AdminResponse::json(200, json!(stub_mapping)))
+        .ok_or_else(|| AdminResponse::error(404, format!("Stub mapping {} not found", id)))
+}
+
+fn edit_stub_mapping(state: &mut State, id: &str, body: &[u8]) -> Result<AdminResponse, AdminResponse> {
+    let id = parse_id(id)?;
+    let mut stub_mapping = parse::<StubMapping>(body)?;
+
+    if !state.mappings.contains_key(&id) {
+        return Err(AdminResponse::error(404, format!("Stub mapping {} not found", id)));
+    }
+
+    stub_mapping.set_id(id);
+    let json = json!(stub_mapping);
+    insert_stub_mapping(state, stub_mapping);
+    Ok(AdminResponse::json(200, json))
+}
+
+fn remove_stub_mapping(state: &mut State, id: &str) -> Result<AdminResponse, AdminResponse> {
+    let id = parse_id(id)?;
+    state.mappings.shift_remove(&id)
+        .map(|_| AdminResponse::ok())
+        .ok_or_else(|| AdminResponse::error(404, format!("Stub mapping {} not found", id)))
+}
+
+fn scenario_possible_states(state: &State, name: &str) -> Option<IndexSet<String>> {
+    let mut possible_states = None;
+
+    for stub_mapping in state.mappings.values() {
+        if stub_mapping.scenario_name() != Some(name) {
+            continue;
+        }
+        let possible_states = possible_states.get_or_insert_with(IndexSet::new);
+        possible_states.extend(stub_mapping.required_scenario_state().map(str::to_string));
+        possible_states.extend(stub_mapping.new_scenario_state().map(str::to_string));
+    }
+
+    possible_states
+}
+
+fn scenarios(state: &State) -> Vec<Value> {
+    let names = state.mappings.values()
+        .filter_map(StubMapping::scenario_name)
+        .collect::<IndexSet<&str>>();
+
+    names.into_iter()
+        .map(|name| {
+            let mappings = state.mappings.values()
+                .filter(|stub_mapping| stub_mapping.scenario_name() == Some(name))
+                .collect::<Vec<&StubMapping>>();
+            let current_state = state.scenario_states.get(name)
+                .map(String::as_str)
+                .unwrap_or(Scenario::STARTED);
+
+            json!({
+                "id": state.scenario_ids[name],
+                "name": name,
+                "state": current_state,
+                "possibleStates": scenario_possible_states(state, name).unwrap_or_default(),
+                "mappings": mappings,
+            })
+        })
+        .collect()
+}
+
+fn set_scenario_state(state: &mut State, name: &str, body: &[u8]) -> Result<AdminResponse, AdminResponse> {
+    let name = percent_decode_str(name).decode_utf8_lossy().into_owned();
+    let possible_states = scenario_possible_states(state, &name)
+        .ok_or_else(|| AdminResponse::error(404, format!("Scenario {} does not exist", name)))?;
+
+    if body.is_empty() {
+        state.scenario_states.remove(&name);
+        return Ok(AdminResponse::ok());
+    }
+
+    let new_state = parse::<Value>(body)?
+        .get("state")
+        .and_then(Value::as_str)
+        .map(str::to_string)
+        .ok_or_else(|| AdminResponse::error(422, "Missing scenario state"))?;
+
+    if !possible_states.contains(&new_state) {
+        return Err(AdminResponse::error(400, format!("Scenario {} does not support state {}", name, new_state)));
+    }
+
+    state.scenario_states.insert(name, new_state);
+    Ok(AdminResponse::ok())
+}
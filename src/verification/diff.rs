@@ -0,0 +1,371 @@
+use std::fmt;
+
+use indexmap::IndexMap;
+
+use crate::http::MultiValue;
+use crate::matching::{evaluation, ContentPattern, RequestPattern, UrlPattern};
+use crate::verification::LoggedRequest;
+
+const NOT_MATCHED_MARKER: &str = "<<<<<";
+
+/// Side-by-side comparison of a request pattern and a logged request.
+///
+/// Every part of the pattern (method, URL, each header, query parameter, cookie and body pattern)
+/// is shown next to the corresponding part of the request and marked if it does not match.
+#[derive(Debug)]
+pub struct Diff<'a> {
+    expected_label: &'a str,
+    request_pattern: &'a RequestPattern,
+    request: &'a LoggedRequest,
+}
+
+/// A single compared part of a [`Diff`](struct.Diff.html).
+#[derive(Debug, Clone)]
+pub struct DiffLine {
+    name: String,
+    expected: String,
+    actual: String,
+    matched: Option<bool>,
+}
+
+impl DiffLine {
+    fn new<N, E, A>(name: N, expected: E, actual: A, matched: Option<bool>) -> DiffLine
+        where N: Into<String>,
+              E: Into<String>,
+              A: Into<String>,
+    {
+        DiffLine {
+            name: name.into(),
+            expected: expected.into(),
+            actual: actual.into(),
+            matched,
+        }
+    }
+
+    /// The compared part, e.g. `URL` or `Header`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+
+    pub fn actual(&self) -> &str {
+        &self.actual
+    }
+
+    /// Whether this part matched or `None` if the pattern can only be evaluated by WireMock.
+    pub fn matched(&self) -> Option<bool> {
+        self.matched
+    }
+
+    pub fn is_unmatched(&self) -> bool {
+        self.matched == Some(false)
+    }
+}
+
+impl<'a> Diff<'a> {
+    /// The expected label is the heading of the request pattern column, e.g. `Closest stub`.
+    pub fn new(expected_label: &'a str, request_pattern: &'a RequestPattern, request: &'a LoggedRequest) -> Diff<'a> {
+        Diff {
+            expected_label,
+            request_pattern,
+            request,
+        }
+    }
+
+    pub fn expected_label(&self) -> &str {
+        self.expected_label
+    }
+
+    pub fn request_pattern(&self) -> &RequestPattern {
+        self.request_pattern
+    }
+
+    pub fn request(&self) -> &LoggedRequest {
+        self.request
+    }
+
+    pub fn lines(&self) -> Vec<DiffLine> {
+        let mut lines = Vec::new();
+
+        lines.push(self.method_line());
+        if let Some(url_pattern) = self.request_pattern.url_pattern() {
+            lines.push(self.url_line(url_pattern));
+        }
+        if let Some(basic_auth_line) = self.basic_auth_line() {
+            lines.push(basic_auth_line);
+        }
+        lines.extend(self.header_lines());
+        lines.extend(self.multi_value_lines("Query", self.request_pattern.query_params(),
+            self.request.query_params()));
+        lines.extend(self.multi_value_lines("Cookie", self.request_pattern.cookies(),
+            self.request.cookies()));
+        lines.extend(self.body_lines());
+        lines.extend(self.multipart_lines());
+        if let Some(custom_matcher_line) = self.custom_matcher_line() {
+            lines.push(custom_matcher_line);
+        }
+
+        lines
+    }
+
+    pub fn unmatched_lines(&self) -> Vec<DiffLine> {
+        self.lines().into_iter()
+            .filter(DiffLine::is_unmatched)
+            .collect()
+    }
+
+    fn method_line(&self) -> DiffLine {
+        let expected = self.request_pattern.method();
+        let actual = self.request.method();
+        let matched = evaluation::match_method(expected, actual);
+
+        DiffLine::new("HTTP method", expected.to_string(), actual.to_string(), Some(matched))
+    }
+
+    fn url_line(&self, url_pattern: &UrlPattern) -> DiffLine {
+        let url = self.request.url();
+        let path = url.split('?').next().unwrap_or(url);
+
+        let (expected, actual, matched) = match url_pattern {
+            UrlPattern::Url(expected) => (expected.clone(), url, Some(expected == url)),
+            UrlPattern::UrlPath(expected) => (expected.clone(), path, Some(expected == path)),
+            UrlPattern::UrlPathPattern(regex) => {
+                (format!("[path regex] {}", regex), path, evaluation::is_full_match(regex, path))
+            },
+            UrlPattern::UrlPattern(regex) => {
+                (format!("[regex] {}", regex), url, evaluation::is_full_match(regex, url))
+            },
+        };
+
+        DiffLine::new("URL", expected, actual, matched)
+    }
+
+    fn basic_auth_line(&self) -> Option<DiffLine> {
+        let credentials = self.request_pattern.basic_auth_credentials()?;
+        let expected = format!("Basic {}",
+            base64::encode(&format!("{}:{}", credentials.username, credentials.password)));
+        let actual = self.request.first_header_str(http::header::AUTHORIZATION);
+        let matched = actual == Some(expected.as_str());
+
+        Some(DiffLine::new("Basic auth", format!("Authorization: {}", expected),
+            actual.map(|actual| format!("Authorization: {}", actual)).unwrap_or_default(),
+            Some(matched)))
+    }
+
+    fn header_lines(&self) -> Vec<DiffLine> {
+        self.request_pattern.headers().iter()
+            .map(|(name, pattern)| {
+                let values = self.request.headers().get_all(name.as_str()).iter()
+                    .map(|value| String::from_utf8_lossy(value.as_bytes()).into_owned())
+                    .collect::<Vec<String>>();
+                multi_value_line("Header", name, pattern, &values)
+            })
+            .collect()
+    }
+
+    fn multi_value_lines<V>(&self, line_name: &str, patterns: &IndexMap<String, ContentPattern>,
+                            actual_values: &IndexMap<String, V>) -> Vec<DiffLine>
+        where V: MultiValue,
+    {
+        patterns.iter()
+            .map(|(name, pattern)| {
+                let values = actual_values.get(name)
+                    .filter(|multi_value| multi_value.is_present())
+                    .map(|multi_value| multi_value.values().to_vec())
+                    .unwrap_or_default();
+                multi_value_line(line_name, name, pattern, &values)
+            })
+            .collect()
+    }
+
+    fn body_lines(&self) -> Vec<DiffLine> {
+        let body = self.request.body();
+
+        self.request_pattern.body_patterns().iter()
+            .map(|pattern| {
+                let actual = match pattern {
+                    ContentPattern::BinaryEqualTo(_) => base64::encode(body),
+                    _ => String::from_utf8_lossy(body).into_owned(),
+                };
+                let matched = evaluation::match_body(pattern, body)
+                    .map(|result| result.is_exact_match());
+                DiffLine::new("Body", format!("[{}]\n{}", pattern.name(), pattern.expected()), actual, matched)
+            })
+            .collect()
+    }
+
+    fn custom_matcher_line(&self) -> Option<DiffLine> {
+        let custom_matcher = self.request_pattern.custom_matcher()?;
+        let expected = if custom_matcher.parameters().is_empty() {
+            format!("[custom matcher] {}", custom_matcher.name())
+        } else {
+            let parameters = serde_json::to_string(custom_matcher.parameters()).unwrap_or_default();
+            format!("[custom matcher] {} {}", custom_matcher.name(), parameters)
+        };
+
+        Some(DiffLine::new("Custom matcher", expected, "", None))
+    }
+
+    fn multipart_lines(&self) -> Vec<DiffLine> {
+        let body = String::from_utf8_lossy(self.request.body());
+
+        self.request_pattern.multipart_patterns().iter()
+            .map(|pattern| {
+                let mut expected = format!("[Multipart {}]", pattern.matching_type());
+                if let Some(name) = pattern.name() {
+                    expected.push_str(&format!(" {}", name));
+                }
+                for (name, header_pattern) in pattern.headers() {
+                    expected.push_str(&format!("\n{} [{}] : {}", name, header_pattern.name(), header_pattern.expected()));
+                }
+                for body_pattern in pattern.body_patterns() {
+                    expected.push_str(&format!("\n[{}]\n{}", body_pattern.name(), body_pattern.expected()));
+                }
+
+                let matched = evaluation::match_multipart(pattern, self.request)
+                    .map(|result| result.is_exact_match());
+                DiffLine::new("Multipart", expected, body.as_ref(), matched)
+            })
+            .collect()
+    }
+}
+
+fn multi_value_line(line_name: &str, name: &str, pattern: &ContentPattern, values: &[String]) -> DiffLine {
+    let matched = evaluation::match_multi_value(pattern, values)
+        .map(|result| result.is_exact_match());
+
+    let expected = format!("{} [{}] : {}", name, pattern.name(), pattern.expected());
+    let actual = if values.is_empty() {
+        String::new()
+    } else {
+        format!("{}: {}", name, values.join(", "))
+    };
+
+    DiffLine::new(line_name, expected, actual, matched)
+}
+
+impl<'a> fmt::Display for Diff<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let lines = self.lines();
+
+        let left_width = lines.iter()
+            .flat_map(|line| line.expected.lines())
+            .map(|expected| expected.chars().count())
+            .chain(std::iter::once(self.expected_label.chars().count()))
+            .max()
+            .unwrap_or(0);
+        let right_width = lines.iter()
+            .flat_map(|line| line.actual.lines())
+            .map(|actual| actual.chars().count())
+            .chain(std::iter::once("Request".len()))
+            .max()
+            .unwrap_or(0);
+        let separator = "-".repeat(left_width + right_width + 3);
+
+        writeln!(f, "{}", separator)?;
+        writeln!(f, "{:left_width$} | Request", self.expected_label, left_width = left_width)?;
+        writeln!(f, "{}", separator)?;
+
+        for line in &lines {
+            let expected_lines = line.expected.lines().collect::<Vec<&str>>();
+            let actual_lines = line.actual.lines().collect::<Vec<&str>>();
+            let row_count = std::cmp::max(1, std::cmp::max(expected_lines.len(), actual_lines.len()));
+
+            for row in 0..row_count {
+                let expected = expected_lines.get(row).cloned().unwrap_or("");
+                let actual = actual_lines.get(row).cloned().unwrap_or("");
+
+                if row == 0 && line.is_unmatched() {
+                    writeln!(f, "{:left_width$} | {:right_width$} {} {} does not match",
+                        expected, actual, NOT_MATCHED_MARKER, line.name,
+                        left_width = left_width, right_width = right_width)?;
+                } else {
+                    writeln!(f, "{:left_width$} | {}", expected, actual, left_width = left_width)?;
+                }
+            }
+        }
+
+        write!(f, "{}", separator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{equal_to, post_requested_for, url_equal_to};
+    use crate::http::RequestMethod;
+    use crate::verification::LoggedRequestBuilder;
+
+    use super::*;
+
+    fn request_pattern() -> RequestPattern {
+        post_requested_for(url_equal_to("/things"))
+            .with_header("Content-Type", equal_to("application/json"))
+            .with_request_body(equal_to("line one\nline two"))
+            .build()
+    }
+
+    fn request() -> LoggedRequest {
+        LoggedRequestBuilder::new(RequestMethod::POST, "/other")
+            .with_header("Content-Type", "application/json")
+            .with_body("line one")
+            .build()
+    }
+
+    #[test]
+    fn pairs_pattern_parts_with_request_parts() {
+        let request_pattern = request_pattern();
+        let request = request();
+        let diff = Diff::new("Expected", &request_pattern, &request);
+
+        let lines = diff.lines();
+        let lines = lines.iter()
+            .map(|line| (line.name(), line.expected(), line.actual(), line.matched()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(lines, vec![
+            ("HTTP method", "POST", "POST", Some(true)),
+            ("URL", "/things", "/other", Some(false)),
+            ("Header", "Content-Type [equalTo] : application/json", "Content-Type: application/json", Some(true)),
+            ("Body", "[equalTo]\nline one\nline two", "line one", Some(false)),
+        ]);
+        let unmatched_names = diff.unmatched_lines().iter()
+            .map(|line| line.name().to_string())
+            .collect::<Vec<String>>();
+        assert_eq!(unmatched_names, ["URL", "Body"]);
+    }
+
+    #[test]
+    fn displays_columns_with_not_matched_markers() {
+        let request_pattern = request_pattern();
+        let request = request();
+        let diff = Diff::new("Closest stub", &request_pattern, &request);
+
+        let separator = "-".repeat(74);
+        let expected = [
+            separator.as_str(),
+            "Closest stub                              | Request",
+            separator.as_str(),
+            "POST                                      | POST",
+            "/things                                   | /other                         <<<<< URL does not match",
+            "Content-Type [equalTo] : application/json | Content-Type: application/json",
+            "[equalTo]                                 | line one                       <<<<< Body does not match",
+            "line one                                  | ",
+            "line two                                  | ",
+            separator.as_str(),
+        ].join("\n");
+        assert_eq!(diff.to_string(), expected);
+    }
+
+    #[test]
+    fn widens_the_expected_column_for_the_label() {
+        let request_pattern = post_requested_for(url_equal_to("/")).build();
+        let request = LoggedRequestBuilder::new(RequestMethod::POST, "/").build();
+        let diff = Diff::new("Expected request pattern", &request_pattern, &request);
+
+        let header = diff.to_string().lines().nth(1).map(String::from);
+        assert_eq!(header.as_deref(), Some("Expected request pattern | Request"));
+    }
+}
@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::verification::NearMiss;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct FindNearMissesResult {
     #[serde(rename = "nearMisses", default)]
     near_misses: Vec<NearMiss>,
@@ -14,8 +14,8 @@ impl FindNearMissesResult {
     }
 }
 
-impl Into<Vec<NearMiss>> for FindNearMissesResult {
-    fn into(self) -> Vec<NearMiss> {
-        self.near_misses
+impl From<FindNearMissesResult> for Vec<NearMiss> {
+    fn from(result: FindNearMissesResult) -> Vec<NearMiss> {
+        result.near_misses
     }
 }
@@ -3,10 +3,10 @@ use serde::{Deserialize, Serialize};
 use crate::verification::LoggedRequest;
 use crate::verification::journal_based_result::{self, JournalBasedResult};
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct FindRequestsResult {
     #[serde(default)]
-    requests: Vec<LoggedRequest>,
+    pub(crate) requests: Vec<LoggedRequest>,
     #[serde(rename = "requestJournalDisabled")]
     request_journal_disabled: bool,
 }
@@ -27,8 +27,8 @@ impl JournalBasedResult for FindRequestsResult {
     }
 }
 
-impl Into<Vec<LoggedRequest>> for FindRequestsResult {
-    fn into(self) -> Vec<LoggedRequest> {
-        self.requests
+impl From<FindRequestsResult> for Vec<LoggedRequest> {
+    fn from(result: FindRequestsResult) -> Vec<LoggedRequest> {
+        result.requests
     }
 }
@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+
+use crate::stubbing::ServeEvent;
+use crate::verification::journal_based_result::{self, JournalBasedResult};
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct FindServeEventsResult {
+    #[serde(rename = "serveEvents", default)]
+    serve_events: Vec<ServeEvent>,
+    #[serde(rename = "requestJournalDisabled", default)]
+    request_journal_disabled: bool,
+}
+
+impl FindServeEventsResult {
+    pub fn serve_events(&self) -> &[ServeEvent] {
+        &self.serve_events
+    }
+}
+
+impl JournalBasedResult for FindServeEventsResult {
+    fn request_journal_disabled(&self) -> bool {
+        self.request_journal_disabled
+    }
+
+    fn assert_request_journal_enabled(&self) {
+        journal_based_result::assert_request_journal_enabled(self.request_journal_disabled);
+    }
+}
+
+impl From<FindServeEventsResult> for Vec<ServeEvent> {
+    fn from(result: FindServeEventsResult) -> Vec<ServeEvent> {
+        result.serve_events
+    }
+}
@@ -1,6 +1,16 @@
+use crate::error::{Error, Result};
+
 pub trait JournalBasedResult {
     fn request_journal_disabled(&self) -> bool;
     fn assert_request_journal_enabled(&self);
+
+    fn check_request_journal_enabled(&self) -> Result<()> {
+        if self.request_journal_disabled() {
+            Err(Error::RequestJournalDisabled)
+        } else {
+            Ok(())
+        }
+    }
 }
 
 pub(crate) fn assert_request_journal_enabled(request_journal_disabled: bool) {
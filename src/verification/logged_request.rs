@@ -11,32 +11,32 @@ use mime::Mime;
 
 use crate::http::{RequestMethod, Cookie, QueryParameter};
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct LoggedRequest {
-    url: String,
+    pub(crate) url: String,
     #[serde(rename = "absoluteUrl")]
-    absolute_url: String,
-    method: RequestMethod,
+    pub(crate) absolute_url: String,
+    pub(crate) method: RequestMethod,
     #[serde(rename = "clientIp")]
-    client_ip: String,
+    pub(crate) client_ip: String,
     #[serde(default, skip_serializing_if = "HeaderMap::is_empty", with = "crate::serde::header_map")]
-    headers: HeaderMap,
-    #[serde(default)]
-    cookies: IndexMap<String, Cookie>,
+    pub(crate) headers: HeaderMap,
+    #[serde(default, deserialize_with = "crate::serde::cookies::deserialize")]
+    pub(crate) cookies: IndexMap<String, Cookie>,
     #[serde(rename = "browserProxyRequest")]
-    browser_proxy_request: bool,
+    pub(crate) browser_proxy_request: bool,
     /// Timestamp epoch millis.
     #[serde(rename = "loggedDate")]
-    logged_date: i64,
-    #[serde(rename = "bodyAsBase64", default, with = "crate::serde::base64")]
-    body: Vec<u8>,
-    scheme: String,
-    host: String,
-    port: u16,
+    pub(crate) logged_date: i64,
+    #[serde(flatten, with = "crate::serde::logged_body")]
+    pub(crate) body: Vec<u8>,
+    pub(crate) scheme: String,
+    pub(crate) host: String,
+    pub(crate) port: u16,
     #[serde(rename = "loggedDateString")]
-    logged_date_string: String,
+    pub(crate) logged_date_string: String,
     #[serde(rename = "queryParams", default)]
-    query_params: IndexMap<String, QueryParameter>,
+    pub(crate) query_params: IndexMap<String, QueryParameter>,
 }
 
 impl LoggedRequest {
@@ -92,7 +92,7 @@ impl LoggedRequest {
     }
 
     #[cfg(feature = "decode")]
-    pub fn decode_body(&self) -> Option<Cow<str>> {
+    pub fn decode_body(&self) -> Option<Cow<'_, str>> {
         self.encoding_from_content_type_header()
             .unwrap_or(UTF_8)
             .decode_without_bom_handling_and_without_replacement(self.body())
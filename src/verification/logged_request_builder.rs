@@ -0,0 +1,134 @@
+use std::time::SystemTime;
+
+use http::{HttpTryFrom, HeaderMap};
+use http::header::{HeaderName, HeaderValue};
+use indexmap::IndexMap;
+use percent_encoding::percent_decode_str;
+
+use crate::http::{Cookie, QueryParameter, RequestMethod};
+use crate::verification::LoggedRequest;
+
+const SCHEME: &str = "http";
+const HOST: &str = "localhost";
+const PORT: u16 = 8080;
+const CLIENT_IP: &str = "127.0.0.1";
+
+/// Builds a request as WireMock would have logged it, e.g. to [`evaluate`] request patterns locally.
+///
+/// Query parameters are parsed from the URL.
+///
+/// [`evaluate`]: ../matching/fn.evaluate.html
+pub struct LoggedRequestBuilder {
+    method: RequestMethod,
+    url: String,
+    headers: HeaderMap,
+    cookies: IndexMap<String, Vec<String>>,
+    body: Vec<u8>,
+}
+
+impl LoggedRequestBuilder {
+    pub fn new<S>(method: RequestMethod, url: S) -> LoggedRequestBuilder
+        where S: Into<String>,
+    {
+        LoggedRequestBuilder {
+            method,
+            url: url.into(),
+            headers: HeaderMap::new(),
+            cookies: IndexMap::new(),
+            body: Vec::new(),
+        }
+    }
+
+    pub fn with_header<K, V>(mut self, key: K, value: V) -> LoggedRequestBuilder
+        where K: AsRef<str>,
+              V: AsRef<str>,
+    {
+        let key_ref = key.as_ref();
+        let header_name = match HeaderName::try_from(key_ref) {
+            Ok(name) => name,
+            Err(_invalid_header_name_error) => {
+                panic!("header name \"{}\" is invalid", key_ref);
+            }
+        };
+
+        let value_ref = value.as_ref();
+        let header_value = match HeaderValue::from_str(value_ref) {
+            Ok(name) => name,
+            Err(_invalid_header_value_error) => {
+                panic!("header value \"{}\" is invalid", value_ref);
+            }
+        };
+
+        self.headers.append(header_name, header_value);
+        self
+    }
+
+    pub fn with_cookie<K, V>(mut self, key: K, value: V) -> LoggedRequestBuilder
+        where K: Into<String>,
+              V: Into<String>,
+    {
+        self.cookies.entry(key.into())
+            .or_insert_with(Vec::new)
+            .push(value.into());
+        self
+    }
+
+    pub fn with_body<B>(mut self, body: B) -> LoggedRequestBuilder
+        where B: Into<Vec<u8>>,
+    {
+        self.body = body.into();
+        self
+    }
+
+    pub fn build(self) -> LoggedRequest {
+        let logged_date = SystemTime::now();
+        let logged_date_millis = logged_date.duration_since(SystemTime::UNIX_EPOCH)
+            .map(|duration| duration.as_millis() as i64)
+            .unwrap_or_default();
+
+        LoggedRequest {
+            absolute_url: format!("{}://{}:{}{}", SCHEME, HOST, PORT, self.url),
+            query_params: parse_query_params(&self.url),
+            url: self.url,
+            method: self.method,
+            client_ip: CLIENT_IP.to_string(),
+            headers: self.headers,
+            cookies: self.cookies.into_iter()
+                .map(|(key, values)| (key.clone(), Cookie::new(key, values)))
+                .collect(),
+            browser_proxy_request: false,
+            logged_date: logged_date_millis,
+            body: self.body,
+            scheme: SCHEME.to_string(),
+            host: HOST.to_string(),
+            port: PORT,
+            logged_date_string: humantime::format_rfc3339_millis(logged_date).to_string(),
+        }
+    }
+}
+
+impl From<LoggedRequestBuilder> for LoggedRequest {
+    fn from(builder: LoggedRequestBuilder) -> LoggedRequest {
+        builder.build()
+    }
+}
+
+fn parse_query_params(url: &str) -> IndexMap<String, QueryParameter> {
+    let mut query_params = IndexMap::<String, Vec<String>>::new();
+
+    let query = url.split_once('?').map(|(_, query)| query).unwrap_or_default();
+    for pair in query.split('&').filter(|pair| !pair.is_empty()) {
+        let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+        query_params.entry(decode(key))
+            .or_default()
+            .push(decode(value));
+    }
+
+    query_params.into_iter()
+        .map(|(key, values)| (key.clone(), QueryParameter::new(key, values)))
+        .collect()
+}
+
+fn decode(value: &str) -> String {
+    percent_decode_str(value).decode_utf8_lossy().into_owned()
+}
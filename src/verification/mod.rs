@@ -1,13 +1,21 @@
 pub use crate::verification::logged_request::LoggedRequest;
+pub use crate::verification::logged_request_builder::LoggedRequestBuilder;
 pub use crate::verification::near_miss::NearMiss;
 pub use crate::verification::journal_based_result::JournalBasedResult;
 pub use crate::verification::verification_result::VerificationResult;
+pub use crate::verification::verification_error::VerificationError;
+pub use crate::verification::diff::{Diff, DiffLine};
 pub use crate::verification::find_requests_result::FindRequestsResult;
 pub use crate::verification::find_near_misses_result::FindNearMissesResult;
+pub use crate::verification::find_serve_events_result::FindServeEventsResult;
 
 mod logged_request;
+mod logged_request_builder;
 mod near_miss;
 mod journal_based_result;
 mod verification_result;
+mod verification_error;
+mod diff;
 mod find_requests_result;
 mod find_near_misses_result;
+mod find_serve_events_result;
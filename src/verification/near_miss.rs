@@ -1,10 +1,10 @@
 use serde::{Deserialize, Serialize};
 
-use crate::verification::LoggedRequest;
+use crate::verification::{Diff, LoggedRequest};
 use crate::stubbing::StubMapping;
 use crate::matching::{RequestPattern, MatchResult};
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct NearMiss {
     request: LoggedRequest,
     #[serde(rename = "stubMapping", skip_serializing_if = "Option::is_none")]
@@ -28,4 +28,13 @@ impl NearMiss {
     pub fn match_result(&self) -> MatchResult {
         self.match_result
     }
+
+    /// Compares the request with the request pattern or stub mapping it was closest to.
+    pub fn diff(&self) -> Option<Diff<'_>> {
+        match (&self.request_pattern, &self.stub_mapping) {
+            (Some(request_pattern), _) => Some(Diff::new("Expected", request_pattern, &self.request)),
+            (None, Some(stub_mapping)) => Some(Diff::new("Closest stub", stub_mapping.request(), &self.request)),
+            (None, None) => None,
+        }
+    }
 }
@@ -0,0 +1,67 @@
+use std::fmt;
+
+use crate::matching::{CountMatchingStrategy, RequestPattern};
+use crate::verification::{Diff, NearMiss};
+
+/// The number of requests matching a pattern did not meet the expected count.
+#[derive(Debug)]
+pub struct VerificationError {
+    expected: CountMatchingStrategy,
+    actual: u32,
+    request_pattern: RequestPattern,
+    near_misses: Vec<NearMiss>,
+}
+
+impl VerificationError {
+    pub(crate) fn new(
+        expected: CountMatchingStrategy,
+        actual: u32,
+        request_pattern: RequestPattern,
+        near_misses: Vec<NearMiss>,
+    ) -> VerificationError {
+        VerificationError {
+            expected,
+            actual,
+            request_pattern,
+            near_misses,
+        }
+    }
+
+    pub fn expected(&self) -> CountMatchingStrategy {
+        self.expected
+    }
+
+    pub fn actual(&self) -> u32 {
+        self.actual
+    }
+
+    pub fn request_pattern(&self) -> &RequestPattern {
+        &self.request_pattern
+    }
+
+    /// The logged requests that came closest to the request pattern.
+    pub fn near_misses(&self) -> &[NearMiss] {
+        &self.near_misses
+    }
+
+    /// Compares the expected request pattern with the closest logged request.
+    pub fn diff(&self) -> Option<Diff<'_>> {
+        self.near_misses.first()
+            .map(|near_miss| Diff::new("Expected", &self.request_pattern, near_miss.request()))
+    }
+}
+
+impl fmt::Display for VerificationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected {} requests matching the following pattern but received {}:\n{}",
+            self.expected, self.actual, self.request_pattern)?;
+
+        if let Some(diff) = self.diff() {
+            write!(f, "\n\nClosest request:\n{}", diff)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for VerificationError {}
@@ -2,12 +2,12 @@ use serde::{Deserialize, Serialize};
 
 use crate::verification::journal_based_result::{self, JournalBasedResult};
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
 pub struct VerificationResult {
     #[serde(with = "crate::serde::u32_negative_to_option")]
-    count: Option<u32>,
+    pub(crate) count: Option<u32>,
     #[serde(rename = "requestJournalDisabled")]
-    request_journal_disabled: bool,
+    pub(crate) request_journal_disabled: bool,
 }
 
 impl VerificationResult {
@@ -0,0 +1,110 @@
+#![cfg(feature = "async")]
+
+use uuid::Uuid;
+
+use wiremock_client::{any, any_url, get, get_requested_for, ok, url_equal_to, AsyncWireMock, Error, WireMockBuilder};
+
+#[tokio::test]
+async fn stub_for_and_remove_stub_mapping() {
+    let wire_mock = create_wire_mock();
+
+    let stub_mapping = wire_mock.stub_for(get(url_equal_to("/some/thing"))
+        .will_return(ok()))
+        .await
+        .unwrap();
+
+    let stub_mapping_removed = wire_mock.remove_stub_mapping(stub_mapping.id()).await.unwrap();
+    assert!(stub_mapping_removed);
+}
+
+#[tokio::test]
+async fn remove_non_existent_stub_mapping() {
+    let wire_mock = create_wire_mock();
+
+    let stub_mapping_removed = wire_mock.remove_stub_mapping(&Uuid::new_v4()).await.unwrap();
+    assert!(!stub_mapping_removed);
+}
+
+#[tokio::test]
+async fn get_stub_mapping() {
+    let wire_mock = create_wire_mock();
+
+    let stub_mapping = wire_mock.stub_for(any(any_url())).await.unwrap();
+
+    let opt_stub_mapping = wire_mock.get_stub_mapping(stub_mapping.id()).await.unwrap();
+    assert_eq!(opt_stub_mapping.unwrap().id(), stub_mapping.id());
+
+    let stub_mapping_removed = wire_mock.remove_stub_mapping(stub_mapping.id()).await.unwrap();
+    assert!(stub_mapping_removed);
+}
+
+#[tokio::test]
+async fn get_non_existent_stub_mapping() {
+    let wire_mock = create_wire_mock();
+
+    let opt_stub_mapping = wire_mock.get_stub_mapping(&Uuid::new_v4()).await.unwrap();
+    assert!(opt_stub_mapping.is_none());
+}
+
+#[tokio::test]
+async fn count_requests_matching() {
+    let wire_mock = create_wire_mock();
+    let url = format!("/test/async/count_requests_matching?id={}", Uuid::new_v4());
+
+    reqwest_async::get(&format!("http://localhost:8181{}", &url)).await.unwrap();
+
+    let count = wire_mock.count(get_requested_for(url_equal_to(url))).await.unwrap();
+    assert_eq!(count, 1);
+}
+
+#[tokio::test]
+async fn verify() {
+    let wire_mock = create_wire_mock();
+    let url = format!("/test/async/verify?id={}", Uuid::new_v4());
+
+    reqwest_async::get(&format!("http://localhost:8181{}", &url)).await.unwrap();
+
+    wire_mock.verify(get_requested_for(url_equal_to(url))).await;
+}
+
+#[tokio::test]
+async fn try_verify() {
+    let wire_mock = create_wire_mock();
+    let url = format!("/test/async/try_verify?id={}", Uuid::new_v4());
+
+    match wire_mock.try_verify(get_requested_for(url_equal_to(url))).await {
+        Err(Error::Verification(error)) => assert_eq!(error.actual(), 0),
+        result => panic!("expected a verification error, got: {:?}", result),
+    }
+}
+
+#[tokio::test]
+async fn can_be_used_from_spawned_tasks() {
+    let wire_mock = std::sync::Arc::new(create_wire_mock());
+
+    let task_wire_mock = wire_mock.clone();
+    let scenarios = tokio::spawn(async move {
+        task_wire_mock.get_scenarios().await
+    }).await.unwrap();
+
+    assert!(scenarios.is_ok());
+}
+
+#[tokio::test]
+async fn put_get_and_delete_file() {
+    let wire_mock = create_wire_mock();
+    let file_name = format!("async/put_get_and_delete_file-{}.txt", Uuid::new_v4());
+
+    wire_mock.put_file(&file_name, "Hello async!").await.unwrap();
+    assert!(wire_mock.list_files().await.unwrap().contains(&file_name));
+    assert_eq!(wire_mock.get_file(&file_name).await.unwrap(), Some(b"Hello async!".to_vec()));
+
+    assert!(wire_mock.delete_file(&file_name).await.unwrap());
+    assert!(wire_mock.get_file(&file_name).await.unwrap().is_none());
+}
+
+fn create_wire_mock() -> AsyncWireMock {
+    WireMockBuilder::new()
+        .port(8181)
+        .build_async()
+}
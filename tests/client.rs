@@ -1,12 +1,20 @@
+use std::collections::HashSet;
+use std::time::{Duration, SystemTime};
+
 use uuid::Uuid;
 
-use wiremock_client::{a_response, any, any_url, containing, equal_to, get, get_requested_for, matching_json_path, no_content, ok, ok_with_body, post, post_requested_for, put, url_equal_to, url_path_equal_to, WireMock, WireMockBuilder, less_than};
+use wiremock_client::{a_multipart, a_response, after, any, any_url, before_now, containing, equal_to, equal_to_json_value, get, get_requested_for, matching_json_path, matching_json_path_with, matching_xpath_with, no_content, not_matching, ok, or, ok_with_body, post, post_requested_for, put, url_equal_to, url_path_equal_to, webhook, WireMock, WireMockBuilder, less_than};
 use wiremock_client::common::metadata;
+use wiremock_client::extension::{Parameters, Template};
 use wiremock_client::global::GlobalSettingsBuilder;
-use wiremock_client::http::DelayDistribution;
+use wiremock_client::recording::{record_spec, RecordingStatus};
+use wiremock_client::http::{DelayDistribution, RequestMethod};
+use wiremock_client::matching::{ContentPattern, DateTimeTruncation, StringValuePattern};
+use wiremock_client::model::{PageParams, PaginatedResult};
 use wiremock_client::stubbing::Scenario;
 use wiremock_client::stubbing::stub_import;
 use wiremock_client::verification::JournalBasedResult;
+use wiremock_client::Error;
 
 macro_rules! string_json_map {
     (@single $($x:tt)*) => (());
@@ -31,8 +39,8 @@ fn stub_for_get_url_equal_to() {
 
     let stub_mapping = wire_mock.stub_for(get(url_equal_to("/some/thing"))).unwrap();
 
-    let stub_mapping_removed = wire_mock.remove_stub_mapping(&stub_mapping.id()).unwrap();
-    assert_eq!(stub_mapping_removed, true);
+    let stub_mapping_removed = wire_mock.remove_stub_mapping(stub_mapping.id()).unwrap();
+    assert!(stub_mapping_removed);
 }
 
 #[test]
@@ -41,8 +49,8 @@ fn stub_for_get_str() {
 
     let stub_mapping = wire_mock.stub_for(get("/some/thing")).unwrap();
 
-    let stub_mapping_removed = wire_mock.remove_stub_mapping(&stub_mapping.id()).unwrap();
-    assert_eq!(stub_mapping_removed, true);
+    let stub_mapping_removed = wire_mock.remove_stub_mapping(stub_mapping.id()).unwrap();
+    assert!(stub_mapping_removed);
 }
 
 #[test]
@@ -51,8 +59,8 @@ fn stub_for_get_string() {
 
     let stub_mapping = wire_mock.stub_for(get("/some/thing".to_string())).unwrap();
 
-    let stub_mapping_removed = wire_mock.remove_stub_mapping(&stub_mapping.id()).unwrap();
-    assert_eq!(stub_mapping_removed, true);
+    let stub_mapping_removed = wire_mock.remove_stub_mapping(stub_mapping.id()).unwrap();
+    assert!(stub_mapping_removed);
 }
 
 #[test]
@@ -65,8 +73,8 @@ fn stub_with_single_header_value_response() {
         .unwrap();
     print_json_value(&wire_mock.get_stub_mapping(stub_mapping.id()).unwrap());
 
-    let stub_mapping_removed = wire_mock.remove_stub_mapping(&stub_mapping.id()).unwrap();
-    assert_eq!(stub_mapping_removed, true);
+    let stub_mapping_removed = wire_mock.remove_stub_mapping(stub_mapping.id()).unwrap();
+    assert!(stub_mapping_removed);
 }
 
 #[test]
@@ -80,8 +88,205 @@ fn stub_with_multi_header_value_response() {
         .unwrap();
     print_json_value(&wire_mock.get_stub_mapping(stub_mapping.id()).unwrap());
 
-    let stub_mapping_removed = wire_mock.remove_stub_mapping(&stub_mapping.id()).unwrap();
-    assert_eq!(stub_mapping_removed, true);
+    let stub_mapping_removed = wire_mock.remove_stub_mapping(stub_mapping.id()).unwrap();
+    assert!(stub_mapping_removed);
+}
+
+#[test]
+fn stub_with_logical_patterns() {
+    let wire_mock = create_wire_mock();
+
+    let stub_mapping = wire_mock.stub_for(get("/some/thing")
+        .with_header("Content-Type", containing("json").and(not_matching(".*legacy.*")))
+        .with_query_param("version", or(vec![equal_to("1"), equal_to("2")])))
+        .unwrap();
+
+    let stub_mapping = wire_mock.get_stub_mapping(stub_mapping.id()).unwrap().unwrap();
+    print_json_value(&stub_mapping);
+    match &stub_mapping.request().headers()["Content-Type"] {
+        ContentPattern::And(pattern) => assert_eq!(pattern.operands().len(), 2),
+        pattern => panic!("expected and pattern, but got: {:?}", pattern),
+    }
+    match &stub_mapping.request().query_params()["version"] {
+        ContentPattern::Or(pattern) => assert_eq!(pattern.value(), "equalTo 1 OR equalTo 2"),
+        pattern => panic!("expected or pattern, but got: {:?}", pattern),
+    }
+
+    let stub_mapping_removed = wire_mock.remove_stub_mapping(stub_mapping.id()).unwrap();
+    assert!(stub_mapping_removed);
+}
+
+#[test]
+fn stub_with_path_sub_matchers() {
+    let wire_mock = create_wire_mock();
+
+    let stub_mapping = wire_mock.stub_for(put("/some/thing")
+        .with_request_body(matching_json_path_with("$..todoItem", containing("wash")))
+        .with_request_body(matching_xpath_with("//todo-item/text()", equal_to("wash car"))))
+        .unwrap();
+
+    let stub_mapping = wire_mock.get_stub_mapping(stub_mapping.id()).unwrap().unwrap();
+    print_json_value(&stub_mapping);
+    match &stub_mapping.request().body_patterns()[0] {
+        ContentPattern::MatchesJsonPath(pattern) => {
+            assert_eq!(pattern.value(), "$..todoItem");
+            assert_eq!(pattern.value_pattern().unwrap().expected(), "wash");
+        },
+        pattern => panic!("expected JSONPath pattern, but got: {:?}", pattern),
+    }
+    match &stub_mapping.request().body_patterns()[1] {
+        ContentPattern::MatchesXPath(pattern) => assert_eq!(pattern.value_pattern().unwrap().name(), "equalTo"),
+        pattern => panic!("expected XPath pattern, but got: {:?}", pattern),
+    }
+
+    let stub_mapping_removed = wire_mock.remove_stub_mapping(stub_mapping.id()).unwrap();
+    assert!(stub_mapping_removed);
+}
+
+#[test]
+fn stub_with_date_time_patterns() {
+    let wire_mock = create_wire_mock();
+
+    let stub_mapping = wire_mock.stub_for(get("/some/thing")
+        .with_header("If-Modified-Since", before_now()
+            .with_actual_format("EEE, dd MMM yyyy HH:mm:ss 'GMT'"))
+        .with_query_param("expires", after("now +3 days")
+            .with_truncate_actual(DateTimeTruncation::FirstDayOfMonth)))
+        .unwrap();
+
+    let stub_mapping = wire_mock.get_stub_mapping(stub_mapping.id()).unwrap().unwrap();
+    print_json_value(&stub_mapping);
+    match &stub_mapping.request().query_params()["expires"] {
+        ContentPattern::After(pattern) => {
+            assert_eq!(pattern.value(), "now +3 days");
+            assert_eq!(pattern.options().truncate_actual(), Some(DateTimeTruncation::FirstDayOfMonth));
+        },
+        pattern => panic!("expected after pattern, but got: {:?}", pattern),
+    }
+
+    let stub_mapping_removed = wire_mock.remove_stub_mapping(stub_mapping.id()).unwrap();
+    assert!(stub_mapping_removed);
+}
+
+#[test]
+fn stub_with_multipart_request_body() {
+    let wire_mock = create_wire_mock();
+
+    let stub_mapping = wire_mock.stub_for(post("/some/upload")
+        .with_multipart_request_body(a_multipart()
+            .with_name("file")
+            .with_body(containing("Content"))))
+        .unwrap();
+
+    let stub_mapping = wire_mock.get_stub_mapping(stub_mapping.id()).unwrap().unwrap();
+    print_json_value(&stub_mapping);
+    let multipart_patterns = stub_mapping.request().multipart_patterns();
+    assert_eq!(multipart_patterns.len(), 1);
+    assert_eq!(multipart_patterns[0].name(), Some("file"));
+    assert!(multipart_patterns[0].is_match_any());
+
+    let stub_mapping_removed = wire_mock.remove_stub_mapping(stub_mapping.id()).unwrap();
+    assert!(stub_mapping_removed);
+}
+
+#[test]
+fn stub_with_custom_matcher() {
+    let wire_mock = create_wire_mock();
+
+    let stub_mapping = wire_mock.stub_for(get("/some/thing")
+        .and_matching("custom-matcher", Parameters::one("param", "value")))
+        .unwrap();
+
+    let stub_mapping = wire_mock.get_stub_mapping(stub_mapping.id()).unwrap().unwrap();
+    print_json_value(&stub_mapping);
+    let custom_matcher = stub_mapping.request().custom_matcher().unwrap();
+    assert_eq!(custom_matcher.name(), "custom-matcher");
+    assert_eq!(custom_matcher.parameters().get_str("param"), Some("value"));
+
+    let stub_mapping_removed = wire_mock.remove_stub_mapping(stub_mapping.id()).unwrap();
+    assert!(stub_mapping_removed);
+}
+
+#[test]
+fn stub_with_json_values() {
+    let wire_mock = create_wire_mock();
+
+    let stub_mapping = wire_mock.stub_for(post("/some/thing")
+        .with_request_body(equal_to_json_value(serde_json::json!({"name": "thing"})))
+        .will_return(ok().with_json_body(serde_json::json!({"id": 1, "tags": ["a", "b"]}))))
+        .unwrap();
+
+    let stub_mapping = wire_mock.get_stub_mapping(stub_mapping.id()).unwrap().unwrap();
+    print_json_value(&stub_mapping);
+    let json = serde_json::to_value(&stub_mapping).unwrap();
+    assert_eq!(json["request"]["bodyPatterns"][0]["equalToJson"], serde_json::json!({"name": "thing"}));
+    assert_eq!(json["response"]["jsonBody"], serde_json::json!({"id": 1, "tags": ["a", "b"]}));
+
+    let stub_mapping_removed = wire_mock.remove_stub_mapping(stub_mapping.id()).unwrap();
+    assert!(stub_mapping_removed);
+}
+
+#[test]
+fn stub_with_response_templating() {
+    let wire_mock = create_wire_mock();
+    let url = format!("/test/stub_with_response_templating/{}", Uuid::new_v4());
+
+    let stub_mapping = wire_mock.stub_for(get(url_path_equal_to(&url))
+        .will_return(ok()
+            .with_templated_header("X-Segment", Template::request_path_segment(1))
+            .with_templated_body(format!("Hello {}!", Template::request_query("name")))))
+        .unwrap();
+
+    let mut response = reqwest::get(&format!("http://localhost:8181{}?name=WireMock", &url)).unwrap();
+    assert_eq!(response.headers()["X-Segment"], "stub_with_response_templating");
+    assert_eq!(response.text().unwrap(), "Hello WireMock!");
+
+    let stub_mapping_removed = wire_mock.remove_stub_mapping(stub_mapping.id()).unwrap();
+    assert!(stub_mapping_removed);
+}
+
+#[test]
+fn stub_with_webhook() {
+    let wire_mock = create_wire_mock();
+
+    let stub_mapping = wire_mock.stub_for(post("/some/thing")
+        .will_return(ok())
+        .with_webhook(webhook()
+            .with_method(RequestMethod::POST)
+            .with_url("http://localhost:8181/callback")
+            .with_body(Template::original_request_body())
+            .with_fixed_delay(10)))
+        .unwrap();
+
+    let stub_mapping = wire_mock.get_stub_mapping(stub_mapping.id()).unwrap().unwrap();
+    print_json_value(&stub_mapping);
+    let webhook = &stub_mapping.post_serve_actions()["webhook"];
+    assert_eq!(webhook.get_str("url"), Some("http://localhost:8181/callback"));
+    assert_eq!(webhook.get_str("body"), Some("{{originalRequest.body}}"));
+
+    let stub_mapping_removed = wire_mock.remove_stub_mapping(stub_mapping.id()).unwrap();
+    assert!(stub_mapping_removed);
+}
+
+#[test]
+fn upload_body_file_and_stub() {
+    let wire_mock = create_wire_mock();
+    let file_name = format!("upload_body_file_and_stub-{}.txt", Uuid::new_v4());
+    let url = format!("/test/upload_body_file_and_stub/{}", Uuid::new_v4());
+
+    let response = wire_mock.upload_body_file_and_stub(&file_name, "Hello from a file!").unwrap();
+    let stub_mapping = wire_mock.stub_for(get(url_equal_to(&url)).will_return(response)).unwrap();
+
+    assert!(wire_mock.list_files().unwrap().contains(&file_name));
+    assert_eq!(wire_mock.get_file(&file_name).unwrap(), Some(b"Hello from a file!".to_vec()));
+    let mut response = reqwest::get(&format!("http://localhost:8181{}", &url)).unwrap();
+    assert_eq!(response.text().unwrap(), "Hello from a file!");
+
+    let file_deleted = wire_mock.delete_file(&file_name).unwrap();
+    assert!(file_deleted);
+    assert!(wire_mock.get_file(&file_name).unwrap().is_none());
+    let stub_mapping_removed = wire_mock.remove_stub_mapping(stub_mapping.id()).unwrap();
+    assert!(stub_mapping_removed);
 }
 
 #[test]
@@ -96,8 +301,8 @@ fn add_and_remove_stub_mapping() {
             .with_status_message("OK")
             .with_body("Hello world!"))).unwrap();
 
-    let stub_mapping_removed = wire_mock.remove_stub_mapping(&stub_mapping.id()).unwrap();
-    assert_eq!(stub_mapping_removed, true);
+    let stub_mapping_removed = wire_mock.remove_stub_mapping(stub_mapping.id()).unwrap();
+    assert!(stub_mapping_removed);
 }
 
 #[test]
@@ -123,8 +328,8 @@ fn add_edit_and_remove_stub_mapping() {
 
     wire_mock.edit_stub_mapping(&edited_stub_mapping).unwrap();
 
-    let stub_mapping_removed = wire_mock.remove_stub_mapping(&edited_stub_mapping.id()).unwrap();
-    assert_eq!(stub_mapping_removed, true);
+    let stub_mapping_removed = wire_mock.remove_stub_mapping(edited_stub_mapping.id()).unwrap();
+    assert!(stub_mapping_removed);
 }
 
 #[test]
@@ -132,7 +337,31 @@ fn remove_non_existent_stub_mapping() {
     let wire_mock = create_wire_mock();
 
     let stub_mapping_removed = wire_mock.remove_stub_mapping(&Uuid::new_v4()).unwrap();
-    assert_eq!(stub_mapping_removed, false);
+    assert!(!stub_mapping_removed);
+}
+
+#[test]
+fn edit_non_existent_stub_mapping() {
+    let wire_mock = create_wire_mock();
+
+    let stub_mapping = get("/some/thing").build();
+
+    match wire_mock.edit_stub_mapping(&stub_mapping) {
+        Err(Error::Http { status, .. }) => assert_eq!(status, http::StatusCode::NOT_FOUND),
+        result => panic!("expected a not found error, got: {:?}", result),
+    }
+}
+
+#[test]
+fn connection_refused() {
+    let wire_mock = WireMockBuilder::new()
+        .port(1)
+        .build();
+
+    match wire_mock.list_stub_mappings() {
+        Err(Error::Transport(_)) => {},
+        result => panic!("expected a transport error, got: {:?}", result),
+    }
 }
 
 #[test]
@@ -145,7 +374,7 @@ fn get_stub_mapping() {
     assert_eq!(opt_stub_mapping.unwrap().id(), stub_mapping.id());
 
     let stub_mapping_removed = wire_mock.remove_stub_mapping(stub_mapping.id()).unwrap();
-    assert_eq!(stub_mapping_removed, true);
+    assert!(stub_mapping_removed);
 }
 
 #[test]
@@ -254,6 +483,73 @@ fn verify_arbitrary_request_count_panic() {
     wire_mock.verify_count(3, get_requested_for(url_equal_to(url)));
 }
 
+#[test]
+fn try_verify_arbitrary_request_count() {
+    let wire_mock = create_wire_mock();
+    let url = format!("/test/try_verify_arbitrary_request_count?id={}", Uuid::new_v4());
+
+    let absolute_url = format!("http://localhost:8181{}", &url);
+    for _ in 0..2 {
+        reqwest::get(&absolute_url).unwrap();
+    }
+
+    wire_mock.try_verify_count(2, get_requested_for(url_equal_to(&url))).unwrap();
+
+    match wire_mock.try_verify_count(3, get_requested_for(url_equal_to(&url))) {
+        Err(Error::Verification(error)) => {
+            assert_eq!(error.expected().to_string(), "exactly 3");
+            assert_eq!(error.actual(), 2);
+            assert!(error.request_pattern().url_pattern().is_some());
+        },
+        result => panic!("expected a verification error, got: {:?}", result),
+    }
+}
+
+#[test]
+fn verify_with_inline_custom_matcher() {
+    let wire_mock = create_wire_mock();
+    let url = format!("/test/verify_with_inline_custom_matcher?id={}", Uuid::new_v4());
+
+    let client = reqwest::Client::new();
+    let absolute_url = format!("http://localhost:8181{}", &url);
+    for token in &["admin", "guest", "admin"] {
+        client.get(&absolute_url)
+            .header("Authorization", format!("Bearer {}", token))
+            .send()
+            .unwrap();
+    }
+
+    let request_pattern = get_requested_for(url_equal_to(&url))
+        .and_matching_fn(|request| request.first_header_str("Authorization") == Some("Bearer admin"))
+        .build();
+    assert_eq!(wire_mock.find(&request_pattern).unwrap().len(), 2);
+    wire_mock.verify_count(2, &request_pattern);
+}
+
+#[test]
+fn verification_error_contains_diff_of_closest_request() {
+    let wire_mock = create_wire_mock();
+    let id = Uuid::new_v4();
+    let url = format!("/test/verification_error_contains_diff_of_closest_request?id={}", id);
+
+    reqwest::get(&format!("http://localhost:8181{}", &url)).unwrap();
+
+    let expected_url = format!("/test/verification_error_contains_diff_of_closest_request?id={}-x", id);
+    match wire_mock.try_verify(get_requested_for(url_equal_to(&expected_url))) {
+        Err(Error::Verification(error)) => {
+            let diff = error.diff().expect("diff of the closest request");
+            let unmatched_lines = diff.unmatched_lines();
+            assert_eq!(unmatched_lines.len(), 1);
+            assert_eq!(unmatched_lines[0].name(), "URL");
+            assert_eq!(unmatched_lines[0].actual(), url);
+
+            let message = error.to_string();
+            assert!(message.contains("<<<<< URL does not match"), "{}", message);
+        },
+        result => panic!("expected a verification error, got: {:?}", result),
+    }
+}
+
 #[test]
 fn verifies_less_than_count_with_less_requests() {
     let wire_mock = create_wire_mock();
@@ -274,6 +570,109 @@ fn get_serve_events() {
     print_json_value(&serve_events);
 }
 
+#[test]
+fn get_serve_events_page() {
+    let wire_mock = create_wire_mock();
+
+    for _ in 0..2 {
+        reqwest::get("http://localhost:8181/test/get_serve_events_page").unwrap();
+    }
+
+    let result = wire_mock.get_serve_events_page(PageParams::default().with_limit(1)).unwrap();
+    assert_eq!(result.serve_events().len(), 1);
+    assert!(result.meta().total() >= 2);
+}
+
+#[test]
+fn get_serve_events_since() {
+    let wire_mock = create_wire_mock();
+    let url = format!("/test/get_serve_events_since?id={}", Uuid::new_v4());
+
+    let since = SystemTime::now() - Duration::from_secs(1);
+    reqwest::get(&format!("http://localhost:8181{}", &url)).unwrap();
+
+    let serve_events = wire_mock.get_serve_events_since(since).unwrap();
+    assert!(serve_events.iter().any(|serve_event| serve_event.request().url() == url));
+
+    let serve_events = wire_mock.get_serve_events_since(SystemTime::now() + Duration::from_secs(60)).unwrap();
+    assert!(serve_events.is_empty());
+}
+
+#[test]
+fn serve_events_pages() {
+    let wire_mock = create_wire_mock();
+
+    for _ in 0..3 {
+        reqwest::get("http://localhost:8181/test/serve_events_pages").unwrap();
+    }
+
+    let pages = wire_mock.serve_events_pages(PageParams::new(2, 0))
+        .take(2)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(pages.len(), 2);
+    assert_eq!(pages[0].len(), 2);
+    assert!(!pages[1].is_empty());
+
+    let ids = pages.iter()
+        .flatten()
+        .map(|serve_event| *serve_event.id())
+        .collect::<Vec<Uuid>>();
+    let unique_ids = ids.iter().collect::<HashSet<&Uuid>>();
+    assert_eq!(unique_ids.len(), ids.len());
+}
+
+#[test]
+fn remove_serve_event() {
+    let wire_mock = create_wire_mock();
+    let url = format!("/test/remove_serve_event?id={}", Uuid::new_v4());
+
+    reqwest::get(&format!("http://localhost:8181{}", &url)).unwrap();
+
+    let serve_event = wire_mock.get_serve_events().unwrap().into_iter()
+        .find(|serve_event| serve_event.request().url() == url)
+        .unwrap();
+
+    let removed_serve_event = wire_mock.remove_serve_event(serve_event.id()).unwrap();
+    assert_eq!(removed_serve_event.unwrap().id(), serve_event.id());
+    assert!(wire_mock.get_served_stub(serve_event.id()).unwrap().is_none());
+    assert!(wire_mock.remove_serve_event(serve_event.id()).unwrap().is_none());
+}
+
+#[test]
+fn remove_serve_events_matching() {
+    let wire_mock = create_wire_mock();
+    let url = format!("/test/remove_serve_events_matching?id={}", Uuid::new_v4());
+
+    let absolute_url = format!("http://localhost:8181{}", &url);
+    for _ in 0..2 {
+        reqwest::get(&absolute_url).unwrap();
+    }
+
+    let removed_serve_events = wire_mock.remove_serve_events_matching(get_requested_for(url_equal_to(&url))).unwrap();
+    assert_eq!(removed_serve_events.len(), 2);
+    assert_eq!(wire_mock.count(get_requested_for(url_equal_to(&url))).unwrap(), 0);
+}
+
+#[test]
+fn remove_serve_events_for_stubs_by_metadata() {
+    let wire_mock = create_wire_mock();
+    let id = Uuid::new_v4().to_string();
+    let url = format!("/test/remove_serve_events_for_stubs_by_metadata?id={}", id);
+
+    let stub_mapping = wire_mock.stub_for(get(url_equal_to(&url))
+        .with_metadata(metadata().attr("remove_serve_events_for_stubs_by_metadata", id.as_str()))
+        .will_return(ok()))
+        .unwrap();
+    reqwest::get(&format!("http://localhost:8181{}", &url)).unwrap();
+
+    let json_path = format!("$[?(@.remove_serve_events_for_stubs_by_metadata == '{}')]", id);
+    let removed_serve_events = wire_mock.remove_serve_events_for_stubs_by_metadata(matching_json_path(json_path)).unwrap();
+    assert_eq!(removed_serve_events.len(), 1);
+
+    wire_mock.remove_stub_mapping(stub_mapping.id()).unwrap();
+}
+
 #[test]
 fn get_served_stub() {
     let wire_mock = create_wire_mock();
@@ -367,7 +766,7 @@ fn find_near_misses_for_request() {
     print_json_value(&near_misses);
 
     let stub_mapping_removed = wire_mock.remove_stub_mapping(stub_mapping.id()).unwrap();
-    assert_eq!(stub_mapping_removed, true);
+    assert!(stub_mapping_removed);
 
     assert!(!near_misses.is_empty());
 }
@@ -389,7 +788,7 @@ fn find_near_misses_for() {
     print_json_value(&near_misses);
 
     let stub_mapping_removed = wire_mock.remove_stub_mapping(stub_mapping.id()).unwrap();
-    assert_eq!(stub_mapping_removed, true);
+    assert!(stub_mapping_removed);
 
     assert!(!near_misses.is_empty());
 }
@@ -411,7 +810,7 @@ fn find_near_misses_for_unmatched_requests() {
     print_json_value(&near_misses);
 
     let stub_mapping_removed = wire_mock.remove_stub_mapping(stub_mapping.id()).unwrap();
-    assert_eq!(stub_mapping_removed, true);
+    assert!(stub_mapping_removed);
 
     assert!(!near_misses.is_empty());
 }
@@ -468,9 +867,9 @@ fn get_scenarios() {
     let scenarios = wire_mock.get_scenarios().unwrap();
     print_json_value(&scenarios);
 
-    assert_eq!(wire_mock.remove_stub_mapping(list_before_add_stub.id()).unwrap(), true);
-    assert_eq!(wire_mock.remove_stub_mapping(add_stub.id()).unwrap(), true);
-    assert_eq!(wire_mock.remove_stub_mapping(list_after_add_stub.id()).unwrap(), true);
+    assert!(wire_mock.remove_stub_mapping(list_before_add_stub.id()).unwrap());
+    assert!(wire_mock.remove_stub_mapping(add_stub.id()).unwrap());
+    assert!(wire_mock.remove_stub_mapping(list_after_add_stub.id()).unwrap());
 
     let to_do_list_scenario = scenarios.iter()
         .find(|scenario| scenario.name() == "To do list")
@@ -480,6 +879,47 @@ fn get_scenarios() {
     assert!(to_do_list_scenario.possible_states().contains("Cancel newspaper item added"));
 }
 
+#[test]
+fn set_and_reset_scenario_state() {
+    let wire_mock = create_wire_mock();
+    let scenario_name = format!("set_and_reset_scenario_state {}", Uuid::new_v4());
+
+    let started_stub = wire_mock.stub_for(get(url_equal_to("/test/set_and_reset_scenario_state"))
+        .in_scenario(scenario_name.as_str())
+        .when_scenario_state_is(Scenario::STARTED)
+        .will_set_state_to("Finished"))
+        .unwrap();
+
+    let scenario_state = |wire_mock: &WireMock| wire_mock.get_scenarios().unwrap().into_iter()
+        .find(|scenario| scenario.name() == scenario_name)
+        .map(|scenario| scenario.state().to_string())
+        .unwrap();
+
+    wire_mock.set_scenario_state(&scenario_name, "Finished").unwrap();
+    assert_eq!(scenario_state(&wire_mock), "Finished");
+
+    match wire_mock.set_scenario_state(&scenario_name, "Undefined") {
+        Err(Error::InvalidScenarioState { state, .. }) => assert_eq!(state, "Undefined"),
+        result => panic!("expected an invalid scenario state error, got: {:?}", result),
+    }
+
+    wire_mock.reset_scenario(&scenario_name).unwrap();
+    assert_eq!(scenario_state(&wire_mock), Scenario::STARTED);
+
+    assert!(wire_mock.remove_stub_mapping(started_stub.id()).unwrap());
+}
+
+#[test]
+fn set_state_of_non_existent_scenario() {
+    let wire_mock = create_wire_mock();
+    let scenario_name = format!("set_state_of_non_existent_scenario {}", Uuid::new_v4());
+
+    match wire_mock.set_scenario_state(&scenario_name, Scenario::STARTED) {
+        Err(Error::ScenarioNotFound { scenario_name: name }) => assert_eq!(name, scenario_name),
+        result => panic!("expected a scenario not found error, got: {:?}", result),
+    }
+}
+
 #[test]
 #[ignore = "updating the global settings my interere with other tests"]
 fn update_and_reset_global_settings() {
@@ -526,7 +966,7 @@ pub fn default_stub_import() {
 
     wire_mock.import_stubs(stub_import()
         .stub(get("/one")
-            .with_id(original_stub.id().clone())
+            .with_id(*original_stub.id())
             .with_metadata(metadata()
                 .attr("overwrites_existing_stubs_by_default", ""))
             .will_return(ok_with_body("Updated")))
@@ -546,7 +986,7 @@ pub fn default_stub_import() {
 
     assert_eq!(imported_stubs.len(), 3);
     for stub in imported_stubs {
-        assert_eq!(wire_mock.remove_stub_mapping(stub.id()).unwrap(), true);
+        assert!(wire_mock.remove_stub_mapping(stub.id()).unwrap());
 
         let request = stub.request();
 
@@ -576,7 +1016,7 @@ pub fn create_and_retrieve_stub_metadata() {
     let retrieved_stub = wire_mock.get_stub_mapping(stub.id())
         .unwrap()
         .unwrap();
-    assert_eq!(wire_mock.remove_stub_mapping(stub.id()).unwrap(), true);
+    assert!(wire_mock.remove_stub_mapping(stub.id()).unwrap());
 
     let metadata = retrieved_stub.metadata();
     print_json_value(&metadata);
@@ -610,8 +1050,8 @@ pub fn can_find_stubs_by_metadata() {
     let json_path = "$..can_find_stubs_by_metadata-four.can_find_stubs_by_metadata-five";
     let stubs = wire_mock.find_stubs_by_metadata(matching_json_path(json_path)).unwrap();
     print_json_value(&stubs);
-    assert_eq!(wire_mock.remove_stub_mapping(stub1.id()).unwrap(), true);
-    assert_eq!(wire_mock.remove_stub_mapping(stub2.id()).unwrap(), true);
+    assert!(wire_mock.remove_stub_mapping(stub1.id()).unwrap());
+    assert!(wire_mock.remove_stub_mapping(stub2.id()).unwrap());
 
     assert_eq!(stubs.len(), 1);
     let retrieved_stub = &stubs[0];
@@ -635,8 +1075,28 @@ pub fn can_remove_stubs_by_metadata() {
     let json_path = "$..can_remove_stubs_by_metadata-four.can_remove_stubs_by_metadata-five";
     wire_mock.remove_stubs_by_metadata(matching_json_path(json_path)).unwrap();
 
-    assert_eq!(wire_mock.remove_stub_mapping(stub1.id()).unwrap(), false);
-    assert_eq!(wire_mock.remove_stub_mapping(stub2.id()).unwrap(), true);
+    assert!(!wire_mock.remove_stub_mapping(stub1.id()).unwrap());
+    assert!(wire_mock.remove_stub_mapping(stub2.id()).unwrap());
+}
+
+#[test]
+fn get_recording_status() {
+    let wire_mock = create_wire_mock();
+
+    let status = wire_mock.get_recording_status().unwrap();
+    assert_ne!(status, RecordingStatus::Recording);
+}
+
+#[test]
+fn snapshot_record_without_proxied_requests() {
+    let wire_mock = create_wire_mock();
+    let url = format!("/test/snapshot_record_without_proxied_requests?id={}", Uuid::new_v4());
+
+    let stub_mappings = wire_mock.snapshot_record(record_spec()
+        .only_requests_matching(get_requested_for(url_equal_to(url)))
+        .make_stubs_persistent(false))
+        .unwrap();
+    assert!(stub_mappings.is_empty());
 }
 
 
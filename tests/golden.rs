@@ -0,0 +1,93 @@
+use std::fs;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+use wiremock_client::http::MultiValue;
+use wiremock_client::model::{GetScenariosResult, GetServeEventsResult, ListStubMappingsResult};
+use wiremock_client::stubbing::{StubImport, StubImportDuplicatePolicy, StubMapping};
+use wiremock_client::verification::{FindNearMissesResult, VerificationResult};
+
+#[test]
+fn stub_mapping() {
+    let stub_mapping = assert_round_trip::<StubMapping>("stub_mapping.json");
+
+    assert_eq!(stub_mapping.name(), Some("Create user"));
+    assert_eq!(stub_mapping.response().transformer_parameters().get_str("greeting"), Some("Hello"));
+}
+
+#[test]
+fn list_stub_mappings() {
+    let result = assert_round_trip::<ListStubMappingsResult>("list_stub_mappings.json");
+
+    assert_eq!(result.mappings().len(), 3);
+}
+
+#[test]
+fn serve_events() {
+    let result = assert_round_trip::<GetServeEventsResult>("serve_events.json");
+
+    let request = result.serve_events()[0].request();
+    assert_eq!(request.body(), br#"{"name":"Tom"}"#);
+    assert_eq!(request.cookies()["tracking"].values(), ["1", "2"]);
+    assert!(result.serve_events()[0].response_definition().is_from_configured_stub());
+}
+
+#[test]
+fn stub_import() {
+    let stub_import = assert_round_trip::<StubImport>("stub_import.json");
+
+    assert_eq!(stub_import.import_options().duplicate_policy(), StubImportDuplicatePolicy::IGNORE);
+    assert!(stub_import.import_options().delete_all_not_in_import());
+}
+
+#[test]
+fn scenarios() {
+    let result = assert_round_trip::<GetScenariosResult>("scenarios.json");
+
+    let possible_states = result.scenarios()[0].possible_states().iter().collect::<Vec<&String>>();
+    assert_eq!(possible_states, ["Started", "User created", "User deleted"]);
+}
+
+#[test]
+fn near_misses() {
+    assert_round_trip::<FindNearMissesResult>("near_misses.json");
+}
+
+#[test]
+fn verification_result() {
+    assert_round_trip::<VerificationResult>("verification_result.json");
+}
+
+/// Deserializes the admin API payload and asserts that serializing it again gives the same JSON.
+fn assert_round_trip<T: Serialize + DeserializeOwned>(file_name: &str) -> T {
+    let path = format!("{}/tests/golden/{}", env!("CARGO_MANIFEST_DIR"), file_name);
+    let json = fs::read_to_string(&path).unwrap();
+    let expected: Value = serde_json::from_str(&json).unwrap();
+
+    let value: T = serde_json::from_str(&json).unwrap();
+    let actual = serde_json::to_value(&value).unwrap();
+
+    assert_eq!(lowercase_header_names(actual), lowercase_header_names(expected), "{}", file_name);
+    value
+}
+
+/// Header names are case insensitive and lowercased by `HeaderMap`.
+fn lowercase_header_names(value: Value) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(map.into_iter()
+            .map(|(key, value)| {
+                let value = match value {
+                    Value::Object(headers) if key == "headers" || key.ends_with("Headers") => Value::Object(headers.into_iter()
+                        .map(|(name, value)| (name.to_lowercase(), lowercase_header_names(value)))
+                        .collect()),
+                    value => lowercase_header_names(value),
+                };
+                (key, value)
+            })
+            .collect()),
+        Value::Array(values) => Value::Array(values.into_iter().map(lowercase_header_names).collect()),
+        value => value,
+    }
+}
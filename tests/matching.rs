@@ -0,0 +1,372 @@
+use wiremock_client::*;
+use std::time::{Duration, SystemTime};
+
+use wiremock_client::extension::Parameters;
+use wiremock_client::http::RequestMethod;
+use wiremock_client::matching::{evaluate, ContentPattern, RequestPattern, DateTimeTruncation, DateTimeUnit, StringValuePattern};
+use wiremock_client::verification::LoggedRequestBuilder;
+
+#[test]
+fn evaluate_exact_match() {
+    let stub_mapping = post(url_path_equal_to("/things"))
+        .with_query_param("search", equal_to("wire mock"))
+        .with_header("Content-Type", containing("json"))
+        .with_cookie("session", matching("[0-9]+"))
+        .with_request_body(equal_to_json(r#"{"name": "thing", "tags": ["a", "b"]}"#)
+            .with_ignore_array_order(true))
+        .with_request_body(matching_json_path("$.tags[1]"))
+        .will_return(ok())
+        .build();
+
+    let request = LoggedRequestBuilder::new(RequestMethod::POST, "/things?search=wire%20mock")
+        .with_header("Content-Type", "application/json")
+        .with_cookie("session", "12345")
+        .with_body(r#"{"tags": ["b", "a"], "name": "thing"}"#)
+        .build();
+
+    assert!(evaluate(stub_mapping.request(), &request).is_exact_match());
+}
+
+#[test]
+fn evaluate_distance() {
+    let request_pattern = get_requested_for(url_equal_to("/some/thing"))
+        .with_header("Accept", equal_to("text/plain"))
+        .build();
+
+    let exact = LoggedRequestBuilder::new(RequestMethod::GET, "/some/thing")
+        .with_header("Accept", "text/plain")
+        .build();
+    let different_header = LoggedRequestBuilder::new(RequestMethod::GET, "/some/thing")
+        .with_header("Accept", "text/html")
+        .build();
+    let different_url = LoggedRequestBuilder::new(RequestMethod::GET, "/other/thing")
+        .with_header("Accept", "text/plain")
+        .build();
+    let different_method = LoggedRequestBuilder::new(RequestMethod::PUT, "/some/thing")
+        .with_header("Accept", "text/plain")
+        .build();
+
+    let exact_result = evaluate(&request_pattern, &exact);
+    let different_header_result = evaluate(&request_pattern, &different_header);
+    let different_url_result = evaluate(&request_pattern, &different_url);
+    let different_method_result = evaluate(&request_pattern, &different_method);
+
+    assert!(exact_result.is_exact_match());
+    assert!(!different_header_result.is_exact_match());
+    assert!(different_header_result.distance() < different_url_result.distance());
+    assert!(different_header_result.distance() < different_method_result.distance());
+}
+
+#[test]
+fn evaluate_absent() {
+    let request_pattern = any_requested_for(any_url())
+        .without_header("Authorization")
+        .build();
+
+    let without_header = LoggedRequestBuilder::new(RequestMethod::GET, "/").build();
+    let with_header = LoggedRequestBuilder::new(RequestMethod::GET, "/")
+        .with_header("Authorization", "Bearer token")
+        .build();
+
+    assert!(evaluate(&request_pattern, &without_header).is_exact_match());
+    assert!(!evaluate(&request_pattern, &with_header).is_exact_match());
+}
+
+#[test]
+fn evaluate_equal_to_json() {
+    let request_pattern = post_requested_for(any_url())
+        .with_request_body(equal_to_json(r#"{"id": "${json-unit.any-string}", "items": [1, 2]}"#))
+        .build();
+
+    let equal = LoggedRequestBuilder::new(RequestMethod::POST, "/")
+        .with_body(r#"{"id": "abc", "items": [1, 2]}"#)
+        .build();
+    let reordered = LoggedRequestBuilder::new(RequestMethod::POST, "/")
+        .with_body(r#"{"id": "abc", "items": [2, 1]}"#)
+        .build();
+    let extra_element = LoggedRequestBuilder::new(RequestMethod::POST, "/")
+        .with_body(r#"{"id": "abc", "items": [1, 2], "extra": true}"#)
+        .build();
+
+    assert!(evaluate(&request_pattern, &equal).is_exact_match());
+    assert!(!evaluate(&request_pattern, &reordered).is_exact_match());
+    assert!(!evaluate(&request_pattern, &extra_element).is_exact_match());
+
+    let lenient_request_pattern = post_requested_for(any_url())
+        .with_request_body(equal_to_json(r#"{"id": "${json-unit.any-string}", "items": [1, 2]}"#)
+            .with_ignore_array_order(true)
+            .with_ignore_extra_elements(true))
+        .build();
+
+    assert!(evaluate(&lenient_request_pattern, &reordered).is_exact_match());
+    assert!(evaluate(&lenient_request_pattern, &extra_element).is_exact_match());
+}
+
+#[test]
+fn evaluate_xml() {
+    let request_pattern = post_requested_for(any_url())
+        .with_request_body(equal_to_xml(r#"<things xmlns="http://example.com/things"><thing id="1">${xmlunit.ignore}</thing></things>"#)
+            .with_enable_placeholders(true))
+        .with_request_body(matching_xpath("//ns:thing[@id='1']")
+            .with_xpath_namespace("ns", "http://example.com/things"))
+        .build();
+
+    let matching_request = LoggedRequestBuilder::new(RequestMethod::POST, "/")
+        .with_body(r#"<things xmlns="http://example.com/things">
+            <thing id="1">anything</thing>
+        </things>"#)
+        .build();
+    let other_request = LoggedRequestBuilder::new(RequestMethod::POST, "/")
+        .with_body(r#"<things xmlns="http://example.com/things"><thing id="2">anything</thing></things>"#)
+        .build();
+    let invalid_request = LoggedRequestBuilder::new(RequestMethod::POST, "/")
+        .with_body("<things>")
+        .build();
+
+    assert!(evaluate(&request_pattern, &matching_request).is_exact_match());
+    assert!(!evaluate(&request_pattern, &other_request).is_exact_match());
+    assert!(!evaluate(&request_pattern, &invalid_request).is_exact_match());
+}
+
+#[test]
+fn evaluate_path_sub_matchers() {
+    let json_request_pattern = post_requested_for(any_url())
+        .with_request_body(matching_json_path_with("$.name", equal_to("thing")))
+        .with_request_body(matching_json_path_with("$.tags", containing("b")))
+        .build();
+    let xml_request_pattern = post_requested_for(any_url())
+        .with_request_body(matching_xpath_with("//todo-item/text()", containing("wash")))
+        .build();
+
+    let json_request = LoggedRequestBuilder::new(RequestMethod::POST, "/")
+        .with_body(r#"{"name": "thing", "tags": ["a", "b"]}"#)
+        .build();
+    let other_json_request = LoggedRequestBuilder::new(RequestMethod::POST, "/")
+        .with_body(r#"{"name": "other", "tags": ["a"]}"#)
+        .build();
+    let xml_request = LoggedRequestBuilder::new(RequestMethod::POST, "/")
+        .with_body("<todo-list><todo-item>buy milk</todo-item><todo-item>wash car</todo-item></todo-list>")
+        .build();
+    let other_xml_request = LoggedRequestBuilder::new(RequestMethod::POST, "/")
+        .with_body("<todo-list><todo-item>buy milk</todo-item></todo-list>")
+        .build();
+
+    assert!(evaluate(&json_request_pattern, &json_request).is_exact_match());
+    assert!(!evaluate(&json_request_pattern, &other_json_request).is_exact_match());
+    assert!(evaluate(&xml_request_pattern, &xml_request).is_exact_match());
+    assert!(!evaluate(&xml_request_pattern, &other_xml_request).is_exact_match());
+}
+
+#[test]
+fn serialize_path_sub_matchers() {
+    let request_pattern = post_requested_for(any_url())
+        .with_request_body(matching_json_path("$.id"))
+        .with_request_body(matching_json_path_with("$.name", equal_to("thing")))
+        .with_request_body(matching_xpath_with("//stuff:inner/text()", containing("wash"))
+            .with_xpath_namespace("stuff", "http://foo.com"))
+        .build();
+
+    let json = serde_json::to_value(&request_pattern).unwrap();
+    assert_eq!(json["bodyPatterns"], serde_json::json!([
+        {"matchesJsonPath": "$.id"},
+        {"matchesJsonPath": {"expression": "$.name", "equalTo": "thing"}},
+        {
+            "matchesXPath": {"expression": "//stuff:inner/text()", "contains": "wash"},
+            "xPathNamespaces": {"stuff": "http://foo.com"},
+        },
+    ]));
+
+    let request_pattern: RequestPattern = serde_json::from_value(json).unwrap();
+    match &request_pattern.body_patterns()[1] {
+        ContentPattern::MatchesJsonPath(pattern) => {
+            assert_eq!(pattern.value(), "$.name");
+            assert_eq!(pattern.value_pattern().unwrap().name(), "equalTo");
+        },
+        pattern => panic!("expected a JSONPath pattern, got: {:?}", pattern),
+    }
+    match &request_pattern.body_patterns()[2] {
+        ContentPattern::MatchesXPath(pattern) => {
+            assert_eq!(pattern.value(), "//stuff:inner/text()");
+            assert_eq!(pattern.value_pattern().unwrap().expected(), "wash");
+        },
+        pattern => panic!("expected an XPath pattern, got: {:?}", pattern),
+    }
+}
+
+#[test]
+fn evaluate_logical_patterns() {
+    let request_pattern = get_requested_for(any_url())
+        .with_header("Content-Type", containing("json").and(not_matching(".*legacy.*")))
+        .with_query_param("version", or(vec![equal_to("1"), equal_to("2")]))
+        .build();
+
+    let matching_request = LoggedRequestBuilder::new(RequestMethod::GET, "/?version=2")
+        .with_header("Content-Type", "application/json")
+        .build();
+    let legacy_request = LoggedRequestBuilder::new(RequestMethod::GET, "/?version=2")
+        .with_header("Content-Type", "application/legacy+json")
+        .build();
+    let other_version_request = LoggedRequestBuilder::new(RequestMethod::GET, "/?version=3")
+        .with_header("Content-Type", "application/json")
+        .build();
+
+    assert!(evaluate(&request_pattern, &matching_request).is_exact_match());
+    assert!(!evaluate(&request_pattern, &legacy_request).is_exact_match());
+    assert!(!evaluate(&request_pattern, &other_version_request).is_exact_match());
+}
+
+#[test]
+fn serialize_logical_patterns() {
+    let pattern: ContentPattern = containing("json").and(not_matching(".*legacy.*")).into();
+
+    let json = serde_json::to_value(&pattern).unwrap();
+    assert_eq!(json, serde_json::json!({"and": [{"contains": "json"}, {"doesNotMatch": ".*legacy.*"}]}));
+
+    let deserialized = serde_json::from_value::<ContentPattern>(json).unwrap();
+    assert_eq!(deserialized.name(), "and");
+    assert_eq!(deserialized.expected(), "contains json AND doesNotMatch .*legacy.*");
+}
+
+#[test]
+fn evaluate_date_time_patterns() {
+    let request_pattern = get_requested_for(any_url())
+        .with_header("If-Modified-Since", before_now())
+        .with_query_param("expires", after("now +3 days"))
+        .with_query_param("day", equal_to_date_time("2021-06-14T00:00:00Z")
+            .with_actual_format("dd/MM/yyyy"))
+        .with_query_param("month", equal_to_date_time("2021-06-01T00:00:00Z")
+            .with_truncate_actual(DateTimeTruncation::FirstDayOfMonth))
+        .build();
+
+    let expires = SystemTime::now() + Duration::from_secs(4 * 24 * 60 * 60);
+    let matching_request = LoggedRequestBuilder::new(RequestMethod::GET,
+            format!("/?expires={}&day=14/06/2021&month=2021-06-14T12:13:14Z", humantime::format_rfc3339(expires)))
+        .with_header("If-Modified-Since", "Wed, 21 Oct 2015 07:28:00 GMT")
+        .build();
+    let expired_request = LoggedRequestBuilder::new(RequestMethod::GET,
+            "/?expires=2015-10-21T07:28:00Z&day=14/06/2021&month=2021-06-14T12:13:14Z")
+        .with_header("If-Modified-Since", "Wed, 21 Oct 2015 07:28:00 GMT")
+        .build();
+
+    assert!(evaluate(&request_pattern, &matching_request).is_exact_match());
+    assert!(!evaluate(&request_pattern, &expired_request).is_exact_match());
+}
+
+#[test]
+fn serialize_date_time_patterns() {
+    let pattern: ContentPattern = before_now()
+        .with_expected_offset(-15, DateTimeUnit::Days)
+        .with_truncate_expected(DateTimeTruncation::FirstHourOfDay)
+        .into();
+
+    let json = serde_json::to_value(&pattern).unwrap();
+    assert_eq!(json, serde_json::json!({
+        "before": "now",
+        "truncateExpected": "first hour of day",
+        "expectedOffset": -15,
+        "expectedOffsetUnit": "days",
+    }));
+
+    let deserialized = serde_json::from_value::<ContentPattern>(json).unwrap();
+    assert_eq!(deserialized.name(), "before");
+
+    let pattern: ContentPattern = after(SystemTime::UNIX_EPOCH).into();
+    assert_eq!(serde_json::to_value(&pattern).unwrap(), serde_json::json!({"after": "1970-01-01T00:00:00Z"}));
+}
+
+#[test]
+fn evaluate_multipart_patterns() {
+    let request_pattern = post_requested_for(url_equal_to("/upload"))
+        .with_any_request_body_part(a_multipart()
+            .with_name("info")
+            .with_header("Content-Type", containing("json"))
+            .with_body(equal_to_json(r#"{"name": "file.txt"}"#)))
+        .with_all_request_body_parts(a_multipart()
+            .with_header("Content-Disposition", containing("form-data")))
+        .build();
+
+    let body = "--boundary\r\n\
+        Content-Disposition: form-data; name=\"info\"\r\n\
+        Content-Type: application/json\r\n\
+        \r\n\
+        {\"name\": \"file.txt\"}\r\n\
+        --boundary\r\n\
+        Content-Disposition: form-data; name=\"file\"; filename=\"file.txt\"\r\n\
+        Content-Type: text/plain\r\n\
+        \r\n\
+        Content\r\n\
+        --boundary--\r\n";
+    let multipart_request = LoggedRequestBuilder::new(RequestMethod::POST, "/upload")
+        .with_header("Content-Type", "multipart/form-data; boundary=boundary")
+        .with_body(body)
+        .build();
+    let json_request = LoggedRequestBuilder::new(RequestMethod::POST, "/upload")
+        .with_header("Content-Type", "application/json")
+        .with_body(r#"{"name": "file.txt"}"#)
+        .build();
+
+    assert!(evaluate(&request_pattern, &multipart_request).is_exact_match());
+    assert!(!evaluate(&request_pattern, &json_request).is_exact_match());
+}
+
+#[test]
+fn serialize_multipart_patterns() {
+    let request_pattern = post_requested_for(url_equal_to("/upload"))
+        .with_all_request_body_parts(a_multipart()
+            .with_name("file")
+            .with_body(binary_equal_to("Content")))
+        .build();
+
+    let json = serde_json::to_value(&request_pattern).unwrap();
+    assert_eq!(json["multipartPatterns"], serde_json::json!([{
+        "name": "file",
+        "matchingType": "ALL",
+        "headers": {"Content-Disposition": {"contains": "name=\"file\""}},
+        "bodyPatterns": [{"binaryEqualTo": "Q29udGVudA=="}],
+    }]));
+}
+
+#[test]
+fn serialize_custom_matcher() {
+    let stub_mapping = request_matching("jwt-matcher", Parameters::one("claim", "admin"))
+        .will_return(ok())
+        .build();
+
+    let json = serde_json::to_value(stub_mapping.request()).unwrap();
+    assert_eq!(json["customMatcher"], serde_json::json!({
+        "name": "jwt-matcher",
+        "parameters": {"claim": "admin"},
+    }));
+
+    let request_pattern = get_requested_for(url_equal_to("/some/thing"))
+        .and_matching("body-length", Parameters::empty())
+        .build();
+
+    let json = serde_json::to_value(&request_pattern).unwrap();
+    assert_eq!(json["customMatcher"], serde_json::json!({"name": "body-length"}));
+    let custom_matcher = request_pattern.custom_matcher().unwrap();
+    assert_eq!(custom_matcher.name(), "body-length");
+    assert!(custom_matcher.parameters().is_empty());
+}
+
+#[test]
+fn evaluate_inline_custom_matcher() {
+    let request_pattern = get_requested_for(url_equal_to("/some/thing"))
+        .and_matching_fn(|request| request.first_header_str("Authorization") == Some("Bearer admin"))
+        .build();
+
+    assert!(request_pattern.has_inline_custom_matcher());
+    let json = serde_json::to_value(&request_pattern).unwrap();
+    assert_eq!(json, serde_json::json!({"method": "GET", "url": "/some/thing"}));
+
+    let request = LoggedRequestBuilder::new(RequestMethod::GET, "/some/thing")
+        .with_header("Authorization", "Bearer admin")
+        .build();
+    assert!(evaluate(&request_pattern, &request).is_exact_match());
+
+    let request = LoggedRequestBuilder::new(RequestMethod::GET, "/some/thing")
+        .with_header("Authorization", "Bearer guest")
+        .build();
+    assert!(!evaluate(&request_pattern, &request).is_exact_match());
+}
+
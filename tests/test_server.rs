@@ -0,0 +1,395 @@
+#![cfg(feature = "test-server")]
+
+use std::fs;
+use std::path::PathBuf;
+
+use uuid::Uuid;
+
+use wiremock_client::{get, get_requested_for, ok, post, url_equal_to, Error};
+use wiremock_client::global::GlobalSettingsBuilder;
+use wiremock_client::http::Body;
+use wiremock_client::model::PageParams;
+use wiremock_client::recording::{record_spec, RecordingStatus};
+use wiremock_client::stubbing::{load_mappings_dir, stub_import, MappingsExportOptions, Scenario, ServeEvent, StubImportDuplicatePolicy};
+use wiremock_client::test_server::TestServer;
+
+#[test]
+fn stub_for_get_and_remove_stub_mapping() {
+    let server = TestServer::start().unwrap();
+    let wire_mock = server.wire_mock();
+
+    let stub_mapping = wire_mock.stub_for(get(url_equal_to("/some/thing"))
+        .will_return(ok()))
+        .unwrap();
+    assert_eq!(server.stub_mappings().len(), 1);
+
+    let opt_stub_mapping = wire_mock.get_stub_mapping(stub_mapping.id()).unwrap();
+    assert_eq!(opt_stub_mapping.as_ref().unwrap().id(), stub_mapping.id());
+    assert_eq!(opt_stub_mapping.as_ref(), Some(&stub_mapping));
+
+    assert!(wire_mock.remove_stub_mapping(stub_mapping.id()).unwrap());
+    assert!(!wire_mock.remove_stub_mapping(stub_mapping.id()).unwrap());
+    assert!(wire_mock.get_stub_mapping(stub_mapping.id()).unwrap().is_none());
+}
+
+#[test]
+fn list_edit_and_reset_stub_mappings() {
+    let server = TestServer::start().unwrap();
+    let wire_mock = server.wire_mock();
+
+    let mut stub_mapping = wire_mock.stub_for(get("/first")).unwrap();
+    wire_mock.stub_for(get("/second")).unwrap();
+    assert_eq!(wire_mock.list_all_stub_mappings().unwrap().mappings().len(), 2);
+
+    stub_mapping.set_name("edited");
+    wire_mock.edit_stub_mapping(&stub_mapping).unwrap();
+    let edited_stub_mapping = wire_mock.get_stub_mapping(stub_mapping.id()).unwrap().unwrap();
+    assert_eq!(edited_stub_mapping.name(), Some("edited"));
+
+    match wire_mock.edit_stub_mapping(&get("/unknown").build()) {
+        Err(Error::Http { status, .. }) => assert_eq!(status, http::StatusCode::NOT_FOUND),
+        result => panic!("expected a not found error, got: {:?}", result),
+    }
+
+    wire_mock.reset_mappings().unwrap();
+    assert!(wire_mock.list_stub_mappings().unwrap().is_empty());
+}
+
+#[test]
+fn import_stubs() {
+    let server = TestServer::start().unwrap();
+    let wire_mock = server.wire_mock();
+
+    wire_mock.stub_for(get("/existing")).unwrap();
+    wire_mock.import_stubs(stub_import()
+        .stub(get("/one"))
+        .stub(post("/two"))
+        .delete_all_existing_stubs_not_in_import())
+        .unwrap();
+
+    assert_eq!(server.stub_mappings().len(), 2);
+}
+
+#[test]
+fn import_stubs_from_dir() {
+    let dir = mappings_dir(&[
+        ("single.json", r#"{"request": {"url": "/one"}, "response": {"body": "one"}}"#),
+        ("nested/multiple.json", r#"{"mappings": [
+            {"id": "8c5db8b0-2db4-4ad7-a99f-38c9b00da3f7", "uuid": "8c5db8b0-2db4-4ad7-a99f-38c9b00da3f7", "request": {"method": "POST", "url": "/two"}, "response": {"status": 201}},
+            {"request": {"url": "/three"}, "response": {"status": 204}}
+        ]}"#),
+        ("ignored.txt", "not a mapping"),
+    ]);
+    let server = TestServer::start().unwrap();
+    let wire_mock = server.wire_mock();
+
+    let stub_mappings = wire_mock.import_stubs_from_dir(&dir, StubImportDuplicatePolicy::IGNORE).unwrap();
+
+    assert_eq!(stub_mappings.len(), 3);
+    assert_eq!(stub_mappings[0].id().to_string(), "8c5db8b0-2db4-4ad7-a99f-38c9b00da3f7");
+    assert_eq!(stub_mappings[1].response().status(), 204);
+    assert_eq!(stub_mappings[2].response().status(), 200);
+    assert_eq!(server.stub_mappings().len(), 3);
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn load_mappings_dir_reports_invalid_file() {
+    let dir = mappings_dir(&[
+        ("valid.json", r#"{"request": {"url": "/valid"}, "response": {}}"#),
+        ("invalid.json", r#"{"mappings": [
+            {"request": {"url": "/valid"}, "response": {}},
+            {"request": {"url": "/invalid"}, "response": {"status": "OK"}}
+        ]}"#),
+    ]);
+
+    match load_mappings_dir(&dir) {
+        Err(Error::InvalidMappingFile { path, json_path, .. }) => {
+            assert_eq!(path, dir.join("invalid.json"));
+            assert_eq!(json_path, "mappings[1].response.status");
+        },
+        result => panic!("expected an invalid mapping file error, got: {:?}", result),
+    }
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn journal_is_empty() {
+    let server = TestServer::start().unwrap();
+    let wire_mock = server.wire_mock();
+
+    assert!(wire_mock.get_serve_events().unwrap().is_empty());
+    assert!(wire_mock.find(get_requested_for(url_equal_to("/some/thing"))).unwrap().is_empty());
+    assert!(wire_mock.find_unmatched().unwrap().is_empty());
+    assert!(wire_mock.find_near_misses_for_unmatched_requests().unwrap().is_empty());
+    assert!(wire_mock.get_served_stub(&Uuid::new_v4()).unwrap().is_none());
+    assert_eq!(wire_mock.count(get_requested_for(url_equal_to("/some/thing"))).unwrap(), 0);
+    wire_mock.reset_requests().unwrap();
+
+    match wire_mock.try_verify(get_requested_for(url_equal_to("/some/thing"))) {
+        Err(Error::Verification(error)) => assert_eq!(error.actual(), 0),
+        result => panic!("expected a verification error, got: {:?}", result),
+    }
+}
+
+#[test]
+fn inline_custom_matcher() {
+    let server = TestServer::start().unwrap();
+    let wire_mock = server.wire_mock();
+    let request_pattern = get_requested_for(url_equal_to("/some/thing"))
+        .and_matching_fn(|request| request.body().is_empty())
+        .build();
+
+    assert_eq!(wire_mock.count(&request_pattern).unwrap(), 0);
+    assert_eq!(wire_mock.count_requests_matching(&request_pattern).unwrap().count(), Some(0));
+    assert!(wire_mock.find_requests_matching(&request_pattern).unwrap().requests().is_empty());
+    match wire_mock.remove_serve_events_matching(&request_pattern) {
+        Err(Error::UnsupportedInlineCustomMatcher) => {},
+        result => panic!("expected an unsupported inline custom matcher error, got: {:?}", result),
+    }
+}
+
+#[test]
+fn serve_events_pages() {
+    let server = TestServer::start().unwrap();
+    let wire_mock = server.wire_mock();
+
+    assert_eq!(wire_mock.serve_events_pages(PageParams::new(2, 0)).count(), 0);
+
+    let mut pages = wire_mock.serve_events_pages(PageParams::new(0, 0));
+    match pages.next() {
+        Some(Err(Error::InvalidPageLimit)) => {},
+        result => panic!("expected an invalid page limit error, got: {:?}", result),
+    }
+    assert!(pages.next().is_none());
+}
+
+#[test]
+fn serve_events_pages_walk_the_whole_journal() {
+    let server = TestServer::start().unwrap();
+    let wire_mock = server.wire_mock();
+    for i in 0..5 {
+        let response = reqwest::get(&format!("{}/things/{}", server.base_url(), i)).unwrap();
+        assert_eq!(response.status().as_u16(), 404);
+    }
+
+    let pages = wire_mock.serve_events_pages(PageParams::default().with_limit(2))
+        .collect::<Result<Vec<Vec<ServeEvent>>, Error>>()
+        .unwrap();
+
+    let urls = pages.iter()
+        .map(|page| page.iter().map(|serve_event| serve_event.request().url()).collect::<Vec<&str>>())
+        .collect::<Vec<Vec<&str>>>();
+    assert_eq!(urls, vec![vec!["/things/4", "/things/3"], vec!["/things/2", "/things/1"], vec!["/things/0"]]);
+    assert_eq!(wire_mock.serve_events_pages(PageParams::default().with_limit(5)).count(), 1);
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn async_serve_events_pages() {
+    use futures_util::TryStreamExt;
+
+    let server = TestServer::start().unwrap();
+    let wire_mock = server.async_wire_mock();
+    for i in 0..3 {
+        reqwest_async::get(&format!("{}/things/{}", server.base_url(), i)).await.unwrap();
+    }
+
+    let pages = wire_mock.serve_events_pages(PageParams::default().with_limit(2))
+        .try_collect::<Vec<Vec<ServeEvent>>>()
+        .await
+        .unwrap();
+    assert_eq!(pages.iter().map(Vec::len).collect::<Vec<usize>>(), vec![2, 1]);
+    assert_eq!(pages[1][0].request().url(), "/things/0");
+}
+
+#[test]
+fn journal_queries() {
+    let server = TestServer::start().unwrap();
+    let wire_mock = server.wire_mock();
+    reqwest::get(&format!("{}/some/thing", server.base_url())).unwrap();
+    reqwest::get(&format!("{}/other/thing", server.base_url())).unwrap();
+
+    assert_eq!(wire_mock.count(get_requested_for(url_equal_to("/some/thing"))).unwrap(), 1);
+    assert_eq!(wire_mock.find_unmatched().unwrap().len(), 2);
+    let serve_event = &wire_mock.get_serve_events().unwrap()[0];
+    assert_eq!(wire_mock.get_served_stub(serve_event.id()).unwrap().as_ref(), Some(serve_event));
+
+    let removed = wire_mock.remove_serve_events_matching(get_requested_for(url_equal_to("/other/thing"))).unwrap();
+    assert_eq!(removed.len(), 1);
+    assert_eq!(wire_mock.get_serve_events().unwrap().len(), 1);
+    wire_mock.reset_requests().unwrap();
+    assert!(wire_mock.get_serve_events().unwrap().is_empty());
+}
+
+#[test]
+fn scenarios() {
+    let server = TestServer::start().unwrap();
+    let wire_mock = server.wire_mock();
+
+    wire_mock.stub_for(get("/todo/items")
+        .in_scenario("To do list")
+        .when_scenario_state_is(Scenario::STARTED)
+        .will_set_state_to("Item added"))
+        .unwrap();
+
+    let scenarios = wire_mock.get_scenarios().unwrap();
+    assert_eq!(scenarios.len(), 1);
+    assert_eq!(scenarios[0].state(), Scenario::STARTED);
+    assert_eq!(scenarios[0].possible_states().len(), 2);
+
+    wire_mock.stub_for(get("/todo/items/done")
+        .in_scenario("To do list")
+        .when_scenario_state_is("Item added"))
+        .unwrap();
+    wire_mock.set_scenario_state("To do list", "Item added").unwrap();
+    let scenario = &wire_mock.get_scenarios().unwrap()[0];
+    assert_eq!(scenario.state(), "Item added");
+    assert_eq!(scenario.id(), scenarios[0].id());
+
+    match wire_mock.set_scenario_state("To do list", "Undefined") {
+        Err(error @ Error::InvalidScenarioState { .. }) => {
+            assert_eq!(error.to_string(), "scenario To do list does not support state Undefined");
+        },
+        result => panic!("expected an invalid scenario state error, got: {:?}", result),
+    }
+    match wire_mock.set_scenario_state("Unknown", Scenario::STARTED) {
+        Err(error @ Error::ScenarioNotFound { .. }) => assert_eq!(error.to_string(), "scenario Unknown does not exist"),
+        result => panic!("expected a scenario not found error, got: {:?}", result),
+    }
+
+    wire_mock.reset_scenario("To do list").unwrap();
+    assert_eq!(wire_mock.get_scenarios().unwrap()[0].state(), Scenario::STARTED);
+}
+
+#[test]
+fn global_settings() {
+    let server = TestServer::start().unwrap();
+    let wire_mock = server.wire_mock();
+
+    wire_mock.update_global_settings(&GlobalSettingsBuilder::new()
+        .fixed_delay(Some(50))
+        .build())
+        .unwrap();
+
+    assert_eq!(wire_mock.get_global_settings().unwrap().fixed_delay(), Some(50));
+}
+
+#[test]
+fn recording_status() {
+    let server = TestServer::start().unwrap();
+    let wire_mock = server.wire_mock();
+
+    assert_eq!(wire_mock.get_recording_status().unwrap(), RecordingStatus::NeverStarted);
+    wire_mock.start_recording("http://example.com").unwrap();
+    assert_eq!(wire_mock.get_recording_status().unwrap(), RecordingStatus::Recording);
+    assert!(wire_mock.stop_recording().unwrap().is_empty());
+    assert_eq!(wire_mock.get_recording_status().unwrap(), RecordingStatus::Stopped);
+}
+
+#[test]
+fn recording_rejects_inline_custom_matcher() {
+    let server = TestServer::start().unwrap();
+    let wire_mock = server.wire_mock();
+    let record_spec = || record_spec()
+        .for_target("http://example.com")
+        .only_requests_matching(get_requested_for(url_equal_to("/some/thing"))
+            .and_matching_fn(|request| request.body().is_empty()));
+
+    match wire_mock.start_recording(record_spec()) {
+        Err(Error::UnsupportedInlineCustomMatcher) => {},
+        result => panic!("expected an unsupported inline custom matcher error, got: {:?}", result),
+    }
+    assert_eq!(wire_mock.get_recording_status().unwrap(), RecordingStatus::NeverStarted);
+    match wire_mock.snapshot_record(record_spec()) {
+        Err(Error::UnsupportedInlineCustomMatcher) => {},
+        result => panic!("expected an unsupported inline custom matcher error, got: {:?}", result),
+    }
+}
+
+#[test]
+fn files() {
+    let server = TestServer::start().unwrap();
+    let wire_mock = server.wire_mock();
+
+    wire_mock.put_file("hello.txt", "Hello world!").unwrap();
+    let response = wire_mock.upload_body_file_and_stub("nested dir/data.bin", vec![0u8, 159, 146, 150])
+        .unwrap()
+        .build();
+    assert_eq!(response.body(), Some(&Body::FileName("nested dir/data.bin".to_string())));
+
+    assert_eq!(wire_mock.list_files().unwrap(), vec!["hello.txt", "nested dir/data.bin"]);
+    assert_eq!(wire_mock.get_file("hello.txt").unwrap(), Some(b"Hello world!".to_vec()));
+    assert_eq!(wire_mock.get_file("nested dir/data.bin").unwrap(), Some(vec![0u8, 159, 146, 150]));
+
+    assert!(wire_mock.delete_file("hello.txt").unwrap());
+    assert!(!wire_mock.delete_file("hello.txt").unwrap());
+    assert!(wire_mock.get_file("hello.txt").unwrap().is_none());
+    assert_eq!(wire_mock.list_files().unwrap(), vec!["nested dir/data.bin"]);
+}
+
+#[test]
+fn export_mappings_to_dir() {
+    let server = TestServer::start().unwrap();
+    let wire_mock = server.wire_mock();
+    let small = wire_mock.stub_for(get("/small").will_return(ok().with_body("small"))).unwrap();
+    wire_mock.stub_for(get("/large")
+        .with_name("Large things!")
+        .will_return(ok()
+            .with_header("Content-Type", "application/xml")
+            .with_body("<things>large</things>")))
+        .unwrap();
+    let dir = mappings_dir(&[]).join("mappings");
+
+    let options = MappingsExportOptions::DEFAULTS.with_extracted_bodies_larger_than(10);
+    let file_paths = wire_mock.export_mappings_to_dir(&dir, options).unwrap();
+
+    assert_eq!(file_paths, vec![
+        dir.join("large-things.json"),
+        dir.join(format!("{}.json", small.id())),
+    ]);
+    let body_file = dir.parent().unwrap().join("__files").join("large-things-body.xml");
+    assert_eq!(fs::read_to_string(body_file).unwrap(), "<things>large</things>");
+
+    let stub_mappings = load_mappings_dir(&dir).unwrap();
+    assert_eq!(stub_mappings.len(), 2);
+    for stub_mapping in stub_mappings {
+        let expected_body = if stub_mapping.id() == small.id() {
+            Body::String("small".to_owned())
+        } else {
+            Body::FileName("large-things-body.xml".to_owned())
+        };
+        assert_eq!(stub_mapping.response().body(), Some(&expected_body));
+    }
+    fs::remove_dir_all(dir.parent().unwrap()).unwrap();
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn async_import_and_export_mappings_dir() {
+    let dir = mappings_dir(&[
+        ("one.json", r#"{"request": {"url": "/one"}, "response": {"body": "one"}}"#),
+    ]);
+    let server = TestServer::start().unwrap();
+    let wire_mock = server.async_wire_mock();
+
+    let stub_mappings = wire_mock.import_stubs_from_dir(&dir, StubImportDuplicatePolicy::IGNORE).await.unwrap();
+    assert_eq!(stub_mappings.len(), 1);
+
+    let export_dir = dir.join("exported");
+    let file_paths = wire_mock.export_mappings_to_dir(&export_dir, MappingsExportOptions::DEFAULTS).await.unwrap();
+    assert_eq!(file_paths, vec![export_dir.join(format!("{}.json", stub_mappings[0].id()))]);
+    assert_eq!(load_mappings_dir(&export_dir).unwrap(), stub_mappings);
+    fs::remove_dir_all(dir).unwrap();
+}
+
+fn mappings_dir(files: &[(&str, &str)]) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("wiremock-client-mappings-{}", Uuid::new_v4()));
+    fs::create_dir_all(&dir).unwrap();
+    for (name, content) in files {
+        let path = dir.join(name);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+    dir
+}